@@ -72,6 +72,12 @@ pub(crate) struct Cli {
     #[clap(long = "stdin", help = "Input text from <STDIN>")]
     pub stdin: bool,
 
+    #[clap(
+        long = "report-unused-directives",
+        help = "Report unused, unknown or unclosed `autocorrect-disable` directives, use with --lint."
+    )]
+    pub report_unused_directives: bool,
+
     #[clap(
         long = "no-diff-bg-color",
         alias = "ndbc",
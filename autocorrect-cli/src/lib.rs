@@ -186,7 +186,12 @@ where
             let lint_errors_count = lint_errors_count.clone();
             let lint_warnings_count = lint_warnings_count.clone();
             let filepath = filepath.to_owned();
-            let filetype = filetype.clone();
+            // Use the file path to format if the type is not specified,
+            // the front matter config may have the relative paths from the document.
+            let filetype = match cli.filetype {
+                Some(_) => filetype.clone(),
+                None => filepath.clone(),
+            };
 
             pool.execute(move || match read_file(&filepath) {
                 Ok(raw) => {
@@ -341,7 +346,13 @@ fn lint_and_output(
     errors_count: &mut usize,
     warings_count: &mut usize,
 ) {
-    let mut result = autocorrect::lint_for(raw, filetype);
+    let mut result = if cli.report_unused_directives {
+        let mut result = autocorrect::lint_for_with_directives(raw, filetype);
+        result.report_directives();
+        result
+    } else {
+        autocorrect::lint_for(raw, filetype)
+    };
     result.filepath = String::from(filepath);
 
     *errors_count += result.errors_count();
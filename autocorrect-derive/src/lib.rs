@@ -17,20 +17,23 @@ pub fn derive_grammar_parser(input: TokenStream) -> TokenStream {
     let struct_name = format_ident!("{}", struct_name);
     let format_fn = format_ident!("format_{}", name);
     let lint_fn = format_ident!("lint_{}", name);
+    let format_or_lint_fn = format_ident!("format_or_lint_{}", name);
 
     quote! {
         #[allow(dead_code)]
         pub fn #format_fn(text: &str) -> FormatResult {{
-            let pairs = #struct_name::parse(Rule::item, text);
-            let text = code::FormatResult::new(text);
-            code::format_pairs(text, pairs)
+            #format_or_lint_fn(code::FormatResult::new(text), text)
         }}
 
         #[allow(dead_code)]
         pub fn #lint_fn(text: &str) -> LintResult {{
+            #format_or_lint_fn(code::LintResult::new(text), text)
+        }}
+
+        #[allow(dead_code)]
+        pub(crate) fn #format_or_lint_fn<O: code::Results>(results: O, text: &str) -> O {{
             let pairs = #struct_name::parse(Rule::item, text);
-            let text = code::LintResult::new(text);
-            code::format_pairs(text, pairs)
+            code::format_pairs(results, pairs)
         }}
     }
     .into()
@@ -101,6 +101,58 @@ fn bench_spellcheck(c: &mut Criterion) {
     });
 }
 
+/// Generate a large spellcheck dictionary config, like `dicts` with 20k words.
+fn large_spellcheck_config(size: usize) -> String {
+    let syllables = [
+        "ka", "lo", "mi", "ne", "ru", "sa", "ti", "vo", "xe", "zu", "bra", "cle", "dro", "fly",
+        "gri", "pha", "sto", "tre",
+    ];
+
+    let mut seed: u64 = 42;
+    let mut config_str = String::from("spellcheck:\n  words:\n");
+    for i in 0..size {
+        let mut word = String::new();
+        for _ in 0..(3 + i % 3) {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            word.push_str(syllables[(seed >> 33) as usize % syllables.len()]);
+        }
+
+        if i % 4 == 0 {
+            config_str.push_str(&format!("    - {word} = {}\n", word.to_uppercase()));
+        } else {
+            config_str.push_str(&format!("    - {word}\n"));
+        }
+    }
+
+    config_str
+}
+
+fn bench_spellcheck_large_dict(c: &mut Criterion) {
+    let config_str = large_spellcheck_config(20_000);
+    let config = Config::from_str(&config_str).unwrap();
+    let text = "探索 apple 充满创新的世界，选购各式 kalomine、sarutivo 和 mac、娱乐产品了，bradroflyka 新款 - stotrexe 新款，并获得相关产品的专家支持服务。";
+
+    c.bench_function("spellcheck_20k_build", |b| {
+        b.iter(|| {
+            let mut config = config.clone();
+            config.prepare();
+            config.spellcheck.matcher().match_keywords("");
+        })
+    });
+
+    c.bench_function("spellcheck_20k_merge", |b| {
+        b.iter(|| Config::default().merge(&config).unwrap())
+    });
+
+    // Make sure the matcher is built before matching.
+    config.spellcheck.matcher();
+    c.bench_function("spellcheck_20k_match", |b| {
+        b.iter(|| config.spellcheck.matcher().match_keywords(text))
+    });
+}
+
 fn bench_markdown(c: &mut Criterion) {
     let raw = include_str!("./fixtures/example.md");
     setup();
@@ -153,7 +205,11 @@ criterion_group!(
     bench_markdown,
 );
 
-criterion_group!(spellcheck_benches, bench_spellcheck);
+criterion_group!(
+    spellcheck_benches,
+    bench_spellcheck,
+    bench_spellcheck_large_dict
+);
 criterion_group!(lint_benches, bench_lint, bench_lint_output);
 
 criterion_main!(format_benches, spellcheck_benches, lint_benches);
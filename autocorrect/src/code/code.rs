@@ -1,6 +1,6 @@
 // autocorrect: false
 use super::*;
-use crate::config::toggle;
+use crate::config::{self, toggle};
 pub use crate::result::*;
 use crate::rule::CJK_RE;
 use pest::error::Error;
@@ -18,16 +18,40 @@ impl<R: RuleType> RuleTypeToString for R {
     }
 }
 
+/// A hook to format / lint the pair in the grammar's own way, e.g.: the section in reStructuredText.
+///
+/// Returns `true` if the pair has been handled, otherwise it will be handled by default.
+pub(crate) type PairHook<R, O> = fn(results: &mut O, pair: &Pair<R>) -> bool;
+
 pub fn format_pairs<R: RuleType, O: Results>(out: O, pairs: Result<Pairs<R>, Error<R>>) -> O {
+    format_pairs_with_hook(out, pairs, |_, _| false)
+}
+
+pub(crate) fn format_pairs_with_hook<R: RuleType, O: Results>(
+    out: O,
+    pairs: Result<Pairs<R>, Error<R>>,
+    hook: PairHook<R, O>,
+) -> O {
     // Limit parse stack max depth for avoiding some complex parser will hangs indefinitely.
     pest::set_call_limit(Some(10_000_000usize.try_into().unwrap()));
 
     let mut out = out;
 
+    // The document config only take effect in this document.
+    let last_document_config = config::document_config();
+
+    // The config of the file type only take effect in this document too.
+    let file_type_config = config::Config::effective().file_type_config(out.file_type());
+    if let Some(file_type_config) = file_type_config {
+        if let Err(err) = config::set_document_config(Some(&file_type_config)) {
+            out.error(&err.to_string());
+        }
+    }
+
     match pairs {
         Ok(pairs) => {
             for pair in pairs {
-                format_pair(&mut out, pair);
+                format_pair(&mut out, pair, hook);
             }
         }
         Err(_err) => {
@@ -35,10 +59,20 @@ pub fn format_pairs<R: RuleType, O: Results>(out: O, pairs: Result<Pairs<R>, Err
         }
     }
 
+    config::restore_document_config(last_document_config);
+
     out
 }
 
-fn format_pair<R: RuleType, O: Results>(results: &mut O, pair: Pair<R>) {
+pub(crate) fn format_pair<R: RuleType, O: Results>(
+    results: &mut O,
+    pair: Pair<R>,
+    hook: PairHook<R, O>,
+) {
+    if hook(results, &pair) {
+        return;
+    }
+
     let rule = pair.as_rule();
     let rule_name = rule.to_string();
     let rule_name = rule_name.as_str();
@@ -53,29 +87,46 @@ fn format_pair<R: RuleType, O: Results>(results: &mut O, pair: Pair<R>) {
         "inline_style" | "inline_javascript" | "codeblock" => {
             format_or_lint_for_inline_scripts(results, pair, rule_name);
         }
+        "meta_config" => {
+            results.ignore(pair.as_str());
+        }
+        "unit_id" => {
+            results.set_unit_id(pair.as_str());
+            results.ignore(pair.as_str());
+        }
+        "pattern" => {
+            format_or_lint_pattern(results, pair);
+        }
         _ => {
             let mut has_child = false;
             let pair_str = pair.as_str();
             let sub_pairs = pair.into_inner();
 
+            // Apply the document config in front matter before format the front matter.
+            if rule_name == "front_matter" {
+                let config_pair = sub_pairs
+                    .clone()
+                    .find(|p| p.as_rule().to_string() == "meta_config");
+                if let Some(config_pair) = config_pair {
+                    apply_document_config(results, config_pair.as_str());
+                }
+            }
+
             // Special hotfix for Markdown block / paragraph / blockquote
             // If they has CJK chars, disable `halfwidth-punctuation` rule temporary.
-            let mut last_toggle = None;
-            if rule_name == "block" && CJK_RE.is_match(pair_str) {
-                last_toggle = Some(results.get_toggle());
-                results.toggle_merge(toggle::Toggle::Disable(vec![
-                    "halfwidth-punctuation".to_owned()
-                ]));
+            let hotfix = rule_name == "block" && CJK_RE.is_match(pair_str);
+            if hotfix {
+                results.toggle_mut().push_override("halfwidth-punctuation");
             }
 
             for child in sub_pairs {
-                format_pair(results, child);
+                format_pair(results, child, hook);
                 has_child = true;
             }
 
-            // Restore toggle if last_toggle is some
-            if let Some(t) = last_toggle {
-                results.toggle(t);
+            // Restore the temporary disabled rule
+            if hotfix {
+                results.toggle_mut().pop_override();
             }
 
             if !has_child {
@@ -87,18 +138,136 @@ fn format_pair<R: RuleType, O: Results>(results: &mut O, pair: Pair<R>) {
 
 /// Format or Lint a matched item
 pub fn format_or_lint<R: RuleType, O: Results>(results: &mut O, rule_name: &str, pair: Pair<R>) {
-    let part = pair.as_str();
     let (line, col) = pair.line_col();
+    format_or_lint_text(
+        results,
+        rule_name,
+        pair.as_str(),
+        (line, col),
+        &Placeables::default(),
+    );
+}
+
+/// Format / Lint the text with placeables, e.g.: `你有{ $count }条消息` in Fluent.
+///
+/// The placeables are kept as is, the rules treat them as English words,
+/// so the spaces around them follow the `space-word` rule, e.g.: `你有 { $count } 条消息`.
+fn format_or_lint_pattern<R: RuleType, O: Results>(results: &mut O, pair: Pair<R>) {
+    let start = pair.as_span().start();
+    let spans = pair
+        .clone()
+        .into_inner()
+        .filter(|p| p.as_rule().to_string() == "placeable")
+        .map(|p| (p.as_span().start() - start, p.as_span().end() - start))
+        .collect::<Vec<_>>();
+
+    let placeables = Placeables::new(pair.as_str(), &spans);
+    format_or_lint_text(
+        results,
+        "pattern",
+        &placeables.text,
+        pair.line_col(),
+        &placeables,
+    );
+}
+
+/// The text with the placeables replaced by the word tokens, they will be restored after format.
+#[derive(Default)]
+struct Placeables {
+    text: String,
+    /// The tokens and the original placeables, the multiline placeable has a token for each line.
+    items: Vec<(String, String)>,
+}
+
+impl Placeables {
+    fn new(text: &str, spans: &[(usize, usize)]) -> Self {
+        // Make sure the token never appears in the text
+        let mut base = String::from("PLACEABLE");
+        while text.contains(&base) {
+            base.push('X');
+        }
+
+        let mut placeables = Placeables::default();
+        let mut last = 0;
+        for (start, end) in spans {
+            placeables.text.push_str(&text[last..*start]);
+            let lines = text[*start..*end]
+                .split('\n')
+                .map(|line| {
+                    if line.is_empty() {
+                        return String::new();
+                    }
+
+                    let token = format!("{base}{}{base}", placeables.items.len());
+                    placeables.items.push((token.clone(), line.to_string()));
+                    token
+                })
+                .collect::<Vec<_>>();
+            placeables.text.push_str(&lines.join("\n"));
+            last = *end;
+        }
+        placeables.text.push_str(&text[last..]);
 
+        placeables
+    }
+
+    fn restore(&self, text: &str) -> String {
+        let mut out = text.to_string();
+        for (token, placeable) in self.items.iter() {
+            out = out.replace(token, placeable);
+        }
+        out
+    }
+}
+
+/// Format / Lint a text, the placeables in the text will be restored in the results.
+fn format_or_lint_text<O: Results>(
+    results: &mut O,
+    rule_name: &str,
+    part: &str,
+    (line, col): (usize, usize),
+    placeables: &Placeables,
+) {
     // Check AutoCorrect enable/disable toggle marker
     // If disable results.is_enabled() will be false
+    let mut toggle = toggle::Toggle::None;
     if rule_name == "comment" || rule_name == "COMMENT" {
-        results.toggle(toggle::parse(part));
+        toggle = toggle::parse(part);
+    }
+
+    if toggle == toggle::Toggle::None {
+        if let Some(directives) = results.directives_mut() {
+            directives.mark_text();
+        }
+    } else {
+        let directive = results
+            .directives_mut()
+            .map(|d| d.push(line, col, part, toggle.clone()));
+        let closed = results.toggle(toggle, directive);
+        if let Some(directives) = results.directives_mut() {
+            directives.close(&closed);
+        }
+    }
+
+    // Limit the rules by the `scopes` config of the file type
+    let kind = config::ScopeKind::from_rule_name(rule_name);
+    let scope_disabled_rules =
+        config::Config::effective().scope_disable_rules(results.file_type(), kind);
+    let in_scope = scope_disabled_rules.is_some();
+
+    let mut disabled_rules = results.get_toggle().disable_rules();
+    for name in scope_disabled_rules.unwrap_or_default() {
+        disabled_rules.insert(name, true);
     }
 
-    let disabled_rules = results.get_toggle().disable_rules();
     if results.is_lint() {
-        // Skip lint if AutoCorrect disabled
+        // Skip lint if AutoCorrect disabled, or out of scope
+        if !in_scope {
+            return;
+        }
+
+        check_directive_used(results, part.split('\n'));
+
         if !results.is_enabled() {
             return;
         }
@@ -138,9 +307,11 @@ pub fn format_or_lint<R: RuleType, O: Results>(results: &mut O, rule_name: &str,
             results.push(LineResult {
                 line: current_line,
                 col: current_col,
-                old: String::from(trimmed),
-                new: line_result.out.trim().to_string(),
+                old: placeables.restore(trimmed),
+                new: placeables.restore(line_result.out.trim()),
                 severity: line_result.severity,
+                message: line_result.messages.join(" "),
+                id: String::new(),
             });
 
             sub_line += 1;
@@ -148,8 +319,8 @@ pub fn format_or_lint<R: RuleType, O: Results>(results: &mut O, rule_name: &str,
     } else {
         let mut new_part = String::from(part);
 
-        // Skip format if AutoCorrect disabled
-        if results.is_enabled() {
+        // Skip format if AutoCorrect disabled, or out of scope
+        if in_scope && results.is_enabled() {
             let lines = part.split('\n');
 
             new_part = lines
@@ -164,16 +335,78 @@ pub fn format_or_lint<R: RuleType, O: Results>(results: &mut O, rule_name: &str,
         results.push(LineResult {
             line,
             col,
-            old: String::from(part),
-            new: new_part,
+            old: placeables.restore(part),
+            new: placeables.restore(&new_part),
             severity: Severity::Pass,
+            message: String::new(),
+            id: String::new(),
         });
     }
 }
 
+/// Apply the `autocorrect:` config in the front matter to this document.
+fn apply_document_config<O: Results>(results: &mut O, part: &str) {
+    // The relative paths in config are from the directory of the document.
+    let base_dir = std::path::Path::new(results.filepath())
+        .parent()
+        .unwrap_or(std::path::Path::new("."));
+
+    match config::parse_front_matter(part, base_dir) {
+        Ok(config::DocumentConfig::Enable(false)) => {
+            results.toggle(toggle::Toggle::Disable(vec![]), None);
+        }
+        Ok(config::DocumentConfig::Enable(true)) => {}
+        Ok(config::DocumentConfig::Config(document_config)) => {
+            if let Err(err) = config::set_document_config(Some(&document_config)) {
+                results.error(&err.to_string());
+            }
+        }
+        Err(err) => results.error(&err.to_string()),
+    }
+}
+
+/// Mark the disable directives in effect as used, if they have suppressed any change in these lines.
+fn check_directive_used<'a, O: Results>(results: &mut O, lines: impl Iterator<Item = &'a str>) {
+    // Only check when the directives are collected for report
+    if results.directives_mut().is_none() {
+        return;
+    }
+
+    let disable_directives = results.get_toggle().disable_directives();
+    let Some(directives) = results.directives_mut() else {
+        return;
+    };
+
+    let pending = disable_directives
+        .into_iter()
+        .filter(|(idx, _)| !directives.items[*idx].used)
+        .collect::<Vec<_>>();
+    if pending.is_empty() {
+        return;
+    }
+
+    for line_str in lines {
+        let line_result = crate::rule::format_or_lint(line_str, true);
+        if line_result.severity.is_pass() {
+            continue;
+        }
+
+        for (idx, disable_rules) in pending.iter() {
+            let suppressed_result =
+                crate::rule::format_or_lint_with_disable_rules(line_str, true, disable_rules);
+            if suppressed_result.out != line_result.out
+                || suppressed_result.severity != line_result.severity
+            {
+                directives.items[*idx].used = true;
+            }
+        }
+    }
+}
+
 /// Format / Lint for the inline scripts.
 ///
 /// For example, The script / style in HTML or Codeblock in Markdown.
+/// The inline scripts inherit the toggle state of the parent.
 fn format_or_lint_for_inline_scripts<R: RuleType, O: Results>(
     results: &mut O,
     pair: Pair<R>,
@@ -183,26 +416,38 @@ fn format_or_lint_for_inline_scripts<R: RuleType, O: Results>(
     let (base_line, _) = pair.line_col();
 
     if results.is_lint() {
+        if let Some(directives) = results.directives_mut() {
+            directives.mark_text();
+        }
+
         // Skip lint if AutoCorrect disabled
         if !results.is_enabled() {
+            // Lint without toggle, to check the disable directives in effect
+            if results.directives_mut().is_some() {
+                if let Some(sub_result) =
+                    lint_inline_script(pair, rule_name, toggle::ToggleStack::default(), None)
+                {
+                    check_directive_used(results, sub_result.lines.iter().map(|l| l.old.as_str()));
+                }
+            }
             return;
         }
 
-        let sub_result = match rule_name {
-            "inline_style" => Some(lint_for(part, "css")),
-            "inline_javascript" => Some(lint_for(part, "js")),
-            "codeblock" => {
-                let codeblock = Codeblock::from_pair(pair);
-                Some(lint_for(&codeblock.code, &codeblock.lang))
-            }
-            _ => None,
-        };
+        let directives = results.directives_mut().map(|d| d.inherit());
+        let sub_result =
+            lint_inline_script(pair, rule_name, results.get_toggle().clone(), directives);
 
         if let Some(result) = sub_result {
             if result.has_error() {
                 results.error(&result.error);
             }
 
+            if let (Some(directives), Some(sub_directives)) =
+                (results.directives_mut(), result.directives)
+            {
+                directives.merge(sub_directives, base_line - 1);
+            }
+
             for mut line in result.lines {
                 // Inline script's lines need add base_line - 1 offset.
                 line.line += base_line - 1;
@@ -214,14 +459,15 @@ fn format_or_lint_for_inline_scripts<R: RuleType, O: Results>(
 
         // Skip format if AutoCorrect disabled
         if results.is_enabled() {
+            let toggle = results.get_toggle();
             let sub_result = match rule_name {
-                "inline_style" => Some(format_for(part, "css")),
-                "inline_javascript" => Some(format_for(part, "js")),
+                "inline_style" => Some(format_inline_script(toggle, part, "css")),
+                "inline_javascript" => Some(format_inline_script(toggle, part, "js")),
                 "codeblock" => {
                     // WARNING: nested codeblock, when call format_for again.
                     // Because codeblock.data has wrap chars, this make overflowed its stack.
                     let mut codeblock = Codeblock::from_pair(pair);
-                    let mut result = format_for(&codeblock.code, &codeblock.lang);
+                    let mut result = format_inline_script(toggle, &codeblock.code, &codeblock.lang);
                     codeblock.update_data(&result.out);
                     result.out = codeblock.data;
                     Some(result)
@@ -244,10 +490,40 @@ fn format_or_lint_for_inline_scripts<R: RuleType, O: Results>(
             old: String::from(part),
             new: new_part,
             severity: Severity::Pass,
+            message: String::new(),
+            id: String::new(),
         });
     }
 }
 
+fn format_inline_script(toggle: &toggle::ToggleStack, code: &str, lang: &str) -> FormatResult {
+    let mut result = FormatResult::new(code);
+    result.toggle = toggle.clone();
+    format_or_lint_for(result, code, lang)
+}
+
+fn lint_inline_script<R: RuleType>(
+    pair: Pair<R>,
+    rule_name: &str,
+    toggle: toggle::ToggleStack,
+    directives: Option<toggle::Directives>,
+) -> Option<LintResult> {
+    let (code, lang) = match rule_name {
+        "inline_style" => (pair.as_str().to_string(), "css".to_string()),
+        "inline_javascript" => (pair.as_str().to_string(), "js".to_string()),
+        "codeblock" => {
+            let codeblock = Codeblock::from_pair(pair);
+            (codeblock.code, codeblock.lang)
+        }
+        _ => return None,
+    };
+
+    let mut result = LintResult::new(&code);
+    result.toggle = toggle;
+    result.directives = directives;
+    Some(format_or_lint_for(result, &code, &lang))
+}
+
 struct Codeblock {
     pub lang: String,
     // All string of codeblock
@@ -427,7 +703,7 @@ mod tests {
         // autocorrect-disable space-word
         // hello世界。
         // autocorrect-disable fullwidth
-        // hello 世界.
+        // hello世界.
         // autocorrect-disable space-word,fullwidth
         // hello世界.
         const a = "hello世界."
@@ -435,9 +711,301 @@ mod tests {
 
         assert_eq!(expected, format_for(raw, "js").out);
         let result = lint_for(raw, "js");
-        assert_eq!(result.lines.len(), 3);
+        assert_eq!(result.lines.len(), 2);
         assert_eq!(result.lines[0].new, "// hello 世界");
         assert_eq!(result.lines[1].new, "// hello世界。");
-        assert_eq!(result.lines[2].new, "// hello 世界.");
+    }
+
+    #[test]
+    fn test_toggle_nesting_markdown() {
+        let raw = indoc! {r#"
+        <!-- autocorrect-disable space-word -->
+        hello世界
+
+        <!-- autocorrect-disable fullwidth -->
+        hello世界.
+
+        <!-- autocorrect-enable fullwidth -->
+
+        hello世界.
+
+        ```js
+        // hello世界
+        // autocorrect-enable
+        // hello世界
+        ```
+
+        hello世界
+
+        <!-- autocorrect-enable -->
+
+        hello世界
+
+        ```js
+        // autocorrect-disable
+        // hello世界
+        ```
+
+        hello世界
+        "#};
+
+        let expected = indoc! {r#"
+        <!-- autocorrect-disable space-word -->
+        hello世界
+
+        <!-- autocorrect-disable fullwidth -->
+        hello世界.
+
+        <!-- autocorrect-enable fullwidth -->
+
+        hello世界。
+
+        ```js
+        // hello世界
+        // autocorrect-enable
+        // hello 世界
+        ```
+
+        hello世界
+
+        <!-- autocorrect-enable -->
+
+        hello 世界
+
+        ```js
+        // autocorrect-disable
+        // hello世界
+        ```
+
+        hello 世界
+        "#};
+
+        assert_eq!(expected, format_for(raw, "md").out);
+
+        let result = lint_for(raw, "md");
+        let lines = result
+            .lines
+            .iter()
+            .map(|l| (l.line, l.new.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (9, "hello世界。"),
+                (14, "// hello 世界"),
+                (21, "hello 世界"),
+                (28, "hello 世界"),
+            ],
+            lines
+        );
+    }
+
+    #[test]
+    fn test_toggle_nesting_html() {
+        let raw = indoc! {r#"
+        <!-- autocorrect-disable space-word -->
+        <p>hello世界</p>
+        <!-- autocorrect-disable -->
+        <p>hello世界.</p>
+        <!-- autocorrect-enable -->
+        <p>hello世界.</p>
+        <script>
+        // hello世界
+        // autocorrect-enable
+        // hello世界
+        </script>
+        <style>
+        /* hello世界 */
+        </style>
+        <p>hello世界</p>
+        <!-- autocorrect-enable space-word -->
+        <p>hello世界</p>
+        "#};
+
+        let expected = indoc! {r#"
+        <!-- autocorrect-disable space-word -->
+        <p>hello世界</p>
+        <!-- autocorrect-disable -->
+        <p>hello世界.</p>
+        <!-- autocorrect-enable -->
+        <p>hello世界。</p>
+        <script>
+        // hello世界
+        // autocorrect-enable
+        // hello 世界
+        </script>
+        <style>
+        /* hello世界 */
+        </style>
+        <p>hello世界</p>
+        <!-- autocorrect-enable space-word -->
+        <p>hello 世界</p>
+        "#};
+
+        assert_eq!(expected, format_for(raw, "html").out);
+
+        let result = lint_for(raw, "html");
+        let lines = result
+            .lines
+            .iter()
+            .map(|l| (l.line, l.new.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (6, "hello世界。"),
+                (10, "// hello 世界"),
+                (17, "hello 世界")
+            ],
+            lines
+        );
+    }
+
+    #[test]
+    fn test_report_directives() {
+        let raw = indoc! {r#"
+        // autocorrect-disable
+        // hello世界
+        // autocorrect-enable
+        // autocorrect-disable fullwidth
+        // hello world
+        // autocorrect-enable
+        // autocorrect-disable spaceword
+        // hello世界
+        // autocorrect-disable space-word
+        // hello世界
+        "#};
+
+        // The directives are not collected by default
+        let mut result = lint_for(raw, "js");
+        assert!(result.directives.is_none());
+        result.report_directives();
+        assert_eq!(1, result.lines.len());
+
+        let mut result = lint_for_with_directives(raw, "js");
+        assert_eq!(1, result.lines.len());
+        result.report_directives();
+
+        let lines = result
+            .lines
+            .iter()
+            .map(|l| (l.line, l.old.as_str(), l.new.as_str(), l.message.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (
+                    4,
+                    "// autocorrect-disable fullwidth",
+                    "// autocorrect-disable fullwidth",
+                    "Unused directive, nothing was suppressed."
+                ),
+                (
+                    7,
+                    "// autocorrect-disable spaceword",
+                    "// autocorrect-disable space-word",
+                    "Unknown rule `spaceword` in directive. Did you mean `space-word`?"
+                ),
+                (
+                    7,
+                    "// autocorrect-disable spaceword",
+                    "// autocorrect-disable spaceword",
+                    "Unused directive, nothing was suppressed."
+                ),
+                (8, "// hello世界", "// hello 世界", ""),
+                (
+                    9,
+                    "// autocorrect-disable space-word",
+                    "// autocorrect-disable space-word",
+                    "Unclosed directive, add `autocorrect-enable` after the disabled content."
+                ),
+            ],
+            lines
+        );
+        assert!(result.lines[..3].iter().all(|l| l.severity.is_warning()));
+    }
+
+    #[test]
+    fn test_report_directives_leading_and_codeblock() {
+        let raw = indoc! {r#"
+        // autocorrect: false
+        // hello世界
+        "#};
+        let mut result = lint_for_with_directives(raw, "js");
+        result.report_directives();
+        assert_eq!(0, result.lines.len());
+
+        let raw = indoc! {r#"
+        <!-- autocorrect-disable -->
+        ```js
+        // hello世界
+        ```
+        <!-- autocorrect-enable -->
+
+        ```js
+        // autocorrect-disable
+        // hello world
+        ```
+        "#};
+        let mut result = lint_for_with_directives(raw, "md");
+        result.report_directives();
+        assert_eq!(1, result.lines.len());
+        assert_eq!(8, result.lines[0].line);
+        assert_eq!("// autocorrect-disable", result.lines[0].old);
+    }
+
+    #[test]
+    fn test_scopes() {
+        let config = config::Config::from_str(indoc! {r#"
+        rules:
+          spellcheck: 1
+        spellcheck:
+          words:
+            - GitHub
+        scopes:
+          javascript: [comment]
+          rust:
+            "*": [comment, string]
+            spellcheck: [comment]
+        "#})
+        .unwrap();
+        let last_config = config::set_document_config(Some(&config)).unwrap();
+
+        let raw = indoc! {r#"
+        // 测试github网站
+        const url = "测试github网站";
+        "#};
+        let expected = indoc! {r#"
+        // 测试 GitHub 网站
+        const url = "测试github网站";
+        "#};
+        assert_eq!(expected, format_for(raw, "js").out);
+        assert_eq!(1, lint_for(raw, "ts").lines.len());
+
+        let raw = indoc! {r#"
+        // 测试github网站
+        let url = "测试github网站";
+        "#};
+        let expected = indoc! {r#"
+        // 测试 GitHub 网站
+        let url = "测试 github 网站";
+        "#};
+        assert_eq!(expected, format_for(raw, "rust").out);
+
+        // The codeblock use the scopes of its file type
+        let raw = indoc! {r#"
+        测试github网站
+
+        ```js
+        const url = "测试github网站";
+        ```
+        "#};
+        let expected = indoc! {r#"
+        测试 GitHub 网站
+
+        ```js
+        const url = "测试github网站";
+        ```
+        "#};
+        assert_eq!(expected, format_for(raw, "markdown").out);
+
+        config::restore_document_config(last_config);
     }
 }
@@ -0,0 +1,102 @@
+// autocorrect: false
+use super::*;
+
+use autocorrect_derive::GrammarParser;
+use pest::Parser as P;
+use pest_derive::Parser;
+
+#[derive(GrammarParser, Parser)]
+#[grammar = "../grammar/fluent.pest"]
+struct FluentParser;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_format_fluent() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        ### 这是Resource注释
+
+        -brand-name = Firefox浏览器
+        # 这是Message注释
+        welcome = 欢迎使用{ -brand-name },你有{ $count }条消息
+        login-input = 登录{ -brand-name }
+            .placeholder = 请输入Email地址
+            .aria-label = 登录Login
+        multiline =
+            这是第1行
+            这是第2行{ $name }
+        emails = { $unread ->
+            [one] 你有1封新Email
+           *[other] 你有{ $unread }封新Email
+        }
+        nested = 版本{ NUMBER($version, minimumFractionDigits: 2) }已发布
+        literal = 使用{ "{" }符号
+        "###};
+
+        let expected = indoc! {r###"
+        ### 这是 Resource 注释
+
+        -brand-name = Firefox 浏览器
+        # 这是 Message 注释
+        welcome = 欢迎使用 { -brand-name }，你有 { $count } 条消息
+        login-input = 登录 { -brand-name }
+            .placeholder = 请输入 Email 地址
+            .aria-label = 登录 Login
+        multiline =
+            这是第 1 行
+            这是第 2 行 { $name }
+        emails = { $unread ->
+            [one] 你有1封新Email
+           *[other] 你有{ $unread }封新Email
+        }
+        nested = 版本 { NUMBER($version, minimumFractionDigits: 2) } 已发布
+        literal = 使用 { "{" } 符号
+        "###};
+
+        assert_eq!(expected, format_for(example, "ftl").to_string());
+    }
+
+    #[test]
+    fn test_format_fluent_with_remove_space_policy() {
+        crate::config::setup_test();
+
+        let config = crate::Config::from_str("spacePolicy: remove").unwrap();
+        let last_config = crate::config::set_document_config(Some(&config)).unwrap();
+
+        let example = "welcome = ようこそ { $name } さん、{ -brand-name } へ\n";
+        let expected = "welcome = ようこそ{ $name }さん、{ -brand-name }へ\n";
+        assert_eq!(expected, format_for(example, "ftl").to_string());
+
+        crate::config::restore_document_config(last_config);
+    }
+
+    #[test]
+    fn test_lint_fluent() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        welcome = 你好
+        messages = 你有{ $count }条消息
+            .title = 消息Title
+        "###};
+
+        let result = lint_for(example, "main.ftl");
+        assert_eq!(
+            vec![
+                (2, 12, "你有{ $count }条消息", "你有 { $count } 条消息"),
+                (3, 14, "消息Title", "消息 Title")
+            ],
+            result
+                .lines
+                .iter()
+                .map(|l| (l.line, l.col, l.old.as_str(), l.new.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,155 @@
+// autocorrect: false
+use super::*;
+
+use autocorrect_derive::GrammarParser;
+use pest::Parser as P;
+use pest_derive::Parser;
+
+#[derive(GrammarParser, Parser)]
+#[grammar = "../grammar/i18n.pest"]
+struct I18nParser;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_format_i18n_arb() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        {
+          "@@locale": "zh",
+          "appTitle": "Flutter示例",
+          "@appTitle": {
+            "description": "应用Title"
+          },
+          "welcome": "你好{name},欢迎使用",
+          "messages": "{count, plural, =0 {没有新消息} one {你有#条新消息} other {你有{count}条新消息}}",
+          "gender": "{sex, select, male {他有{count, plural, one {1个Apple} other {#个Apple}}} other {其他}}",
+          "quoted": "使用'{'符号",
+          "quotedHash": "共{count, plural, other {#个'#'号}}",
+          "multiline": "第1行\n第2行",
+          "literal": "使用{符号"
+        }
+        "###};
+
+        let expected = indoc! {r###"
+        {
+          "@@locale": "zh",
+          "appTitle": "Flutter 示例",
+          "@appTitle": {
+            "description": "应用Title"
+          },
+          "welcome": "你好 {name}，欢迎使用",
+          "messages": "{count, plural, =0 {没有新消息} one {你有 # 条新消息} other {你有 {count} 条新消息}}",
+          "gender": "{sex, select, male {他有{count, plural, one {1 个 Apple} other {# 个 Apple}}} other {其他}}",
+          "quoted": "使用'{'符号",
+          "quotedHash": "共{count, plural, other {# 个'#'号}}",
+          "multiline": "第 1 行\n第 2 行",
+          "literal": "使用{符号"
+        }
+        "###};
+
+        assert_eq!(expected, format_for(example, "app_zh.arb").to_string());
+    }
+
+    #[test]
+    fn test_format_i18n_json() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        {
+          "Hello世界": "你好世界",
+          "nav": {
+            "home": "首页Home",
+            "items": ["你有{{count}}条消息", "查看$t(nav.home)页面", 100, true]
+          }
+        }
+        "###};
+
+        let expected = indoc! {r###"
+        {
+          "Hello世界": "你好世界",
+          "nav": {
+            "home": "首页 Home",
+            "items": ["你有 {{count}} 条消息", "查看 $t(nav.home) 页面", 100, true]
+          }
+        }
+        "###};
+
+        assert_eq!(expected, format_for(example, "i18n").to_string());
+    }
+
+    #[test]
+    fn test_format_i18n_yaml() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        # 中文Locale
+        zh-CN:
+          hello世界: 你好世界
+          welcome: 你好%{name},欢迎使用
+          messages: "{count, plural, one {#条消息} other {#条消息}}"
+          single: '你好''Rails''应用'
+          comment: 你好Rails # 这是comment
+          months:
+            - 1月January
+            - 2月February
+          default: &default 默认Value
+          description: |
+            第1行
+            第2行
+
+          footer: 页脚Footer
+        "###};
+
+        let expected = indoc! {r###"
+        # 中文 Locale
+        zh-CN:
+          hello世界: 你好世界
+          welcome: 你好 %{name}，欢迎使用
+          messages: "{count, plural, one {# 条消息} other {# 条消息}}"
+          single: '你好''Rails''应用'
+          comment: 你好 Rails # 这是comment
+          months:
+            - 1 月 January
+            - 2 月 February
+          default: &default 默认Value
+          description: |
+            第 1 行
+            第 2 行
+
+          footer: 页脚 Footer
+        "###};
+
+        assert_eq!(expected, format_for(example, "i18n").to_string());
+    }
+
+    #[test]
+    fn test_lint_i18n() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        {
+          "title": "你好",
+          "messages": "{count, plural, one {1条消息} other {#条消息}}"
+        }
+        "###};
+
+        let result = lint_for(example, "app_zh.arb");
+        assert_eq!(
+            vec![
+                (3, 37, "1条消息", "1 条消息"),
+                (3, 50, "#条消息", "# 条消息")
+            ],
+            result
+                .lines
+                .iter()
+                .map(|l| (l.line, l.col, l.old.as_str(), l.new.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+}
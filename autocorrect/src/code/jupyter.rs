@@ -84,6 +84,8 @@ pub fn lint_jupyter(input: &str) -> LintResult {
                             new: line.new,
                             old: line.old,
                             severity: line.severity,
+                            message: line.message,
+                            id: line.id,
                         });
                     }
                 }
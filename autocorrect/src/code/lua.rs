@@ -0,0 +1,92 @@
+// autocorrect: false
+use super::*;
+use autocorrect_derive::GrammarParser;
+use pest::Parser as P;
+use pest_derive::Parser;
+
+#[derive(GrammarParser, Parser)]
+#[grammar = "../grammar/lua.pest"]
+struct LuaParser;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_format_lua() {
+        let example = indoc! {r###"
+        --[[
+        这是多行1注释
+        这是多行2注释
+        ]]
+        --[==[
+        包含 ]] 的第3个注释
+        ]==]
+        local ui = {
+          title = "你好hello世界",
+          tips = '第1个提示',
+          escaped = "包含\"引号\"的Text",
+          content = [[
+            第1行多行字符串
+            第2行多行字符串
+          ]],
+          nested = [=[包含[[long]]字符串]=],
+        }
+
+        -- 第4个注释
+        print(t[1] .. "共" .. count .. "个Item")
+        local n = string.match(text, "(%d+)个Item")
+        local s = text:gsub("第%d+章", "第1章")
+        "###};
+
+        let expect = indoc! {r###"
+        --[[
+        这是多行 1 注释
+        这是多行 2 注释
+        ]]
+        --[==[
+        包含 ]] 的第 3 个注释
+        ]==]
+        local ui = {
+          title = "你好 hello 世界",
+          tips = '第 1 个提示',
+          escaped = "包含\"引号\"的 Text",
+          content = [[
+            第 1 行多行字符串
+            第 2 行多行字符串
+          ]],
+          nested = [=[包含 [[long]] 字符串]=],
+        }
+
+        -- 第 4 个注释
+        print(t[1] .. "共" .. count .. "个 Item")
+        local n = string.match(text, "(%d+)个Item")
+        local s = text:gsub("第%d+章", "第1章")
+        "###};
+
+        assert_eq!(expect, format_for(example, "lua").to_string());
+    }
+
+    #[test]
+    fn it_lint_lua() {
+        let example = indoc! {r###"
+        -- 第1个注释
+        local title = [==[你好hello]==]
+        "###};
+
+        let result = lint_for(example, "main.lua");
+        assert_eq!(
+            vec![
+                (1, 1, "-- 第1个注释", "-- 第 1 个注释"),
+                (2, 15, "[==[你好hello]==]", "[==[你好 hello]==]")
+            ],
+            result
+                .lines
+                .iter()
+                .map(|l| (l.line, l.col, l.old.as_str(), l.new.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -349,6 +349,187 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_front_matter_config() {
+        crate::config::setup_test();
+
+        let raw = indoc! { r###"
+        ---
+        title: 你好world
+        autocorrect:
+          rules:
+            fullwidth: off
+            spellcheck: error
+          spellcheck:
+            words:
+              - AutoCorrect
+        ---
+
+        测试autocorrect和ios的关系.
+
+        ```rb
+        # 代码块里面的autocorrect也会生效.
+        ```
+        "### };
+
+        let expected = indoc! { r###"
+        ---
+        title: 你好 world
+        autocorrect:
+          rules:
+            fullwidth: off
+            spellcheck: error
+          spellcheck:
+            words:
+              - AutoCorrect
+        ---
+
+        测试 AutoCorrect 和 iOS 的关系.
+
+        ```rb
+        # 代码块里面的 AutoCorrect 也会生效.
+        ```
+        "### };
+
+        assert_eq!(expected, format_for(raw, "markdown").out);
+
+        // The document config should not leak to other documents.
+        assert_eq!(
+            "测试 autocorrect 和 ios 的关系。",
+            format_for("测试autocorrect和ios的关系.", "markdown").out
+        );
+
+        let lint_result = lint_for(raw, "markdown");
+        assert_eq!(3, lint_result.lines.len());
+        assert_eq!("测试 AutoCorrect 和 iOS 的关系.", lint_result.lines[1].new);
+
+        let raw = indoc! { r###"
+        ---
+        title: 你好world
+        autocorrect: false
+        ---
+
+        测试autocorrect和ios的关系.
+        "### };
+        assert_eq!(raw, format_for(raw, "markdown").out);
+        assert_eq!(0, lint_for(raw, "markdown").lines.len());
+
+        let raw = indoc! { r###"
+        ---
+        autocorrect:
+          rules: foo
+        ---
+
+        测试autocorrect
+        "### };
+        let result = format_for(raw, "markdown");
+        assert_eq!(raw, result.out);
+        assert!(result.error.starts_with("Front matter config parse error"));
+    }
+
+    #[test]
+    fn test_front_matter_config_only_at_beginning() {
+        let raw = indoc! { r###"
+        测试autocorrect
+
+        ---
+        autocorrect:
+          rules:
+            space-word: 0
+        ---
+
+        测试autocorrect
+        "### };
+
+        let expected = indoc! { r###"
+        测试 autocorrect
+
+        ---
+        autocorrect:
+          rules:
+            space-word: 0
+        ---
+
+        测试 autocorrect
+        "### };
+
+        // The config block in the middle of document is not front matter
+        let result = format_for(raw, "markdown");
+        assert_eq!("", result.error);
+        assert_eq!(expected, result.out);
+    }
+
+    #[test]
+    fn test_front_matter_config_dicts() {
+        let raw = indoc! { r###"
+        ---
+        autocorrect:
+          rules:
+            spellcheck: error
+          spellcheck:
+            dicts:
+              - ./dict/brands.txt
+        ---
+
+        测试github和typescript
+        "### };
+
+        // The relative dicts are from the directory of the document
+        let filepath = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/doc.md");
+        let result = format_for(raw, filepath);
+        assert_eq!("", result.error);
+        assert!(result.out.ends_with("测试 GitHub 和 TypeScript\n"));
+
+        let result = format_for(raw, "markdown");
+        assert_eq!(raw, result.out);
+        assert!(result.error.starts_with("Spellcheck dict file not found"));
+    }
+
+    #[test]
+    fn test_lint_terms() {
+        let raw = indoc! { r###"
+        ---
+        autocorrect:
+          terms:
+            - pattern: 点击这里
+              message: Use descriptive link text.
+              severity: warning
+            - pattern: 傻瓜式
+              replacement: 简单易用
+        ---
+
+        [点击这里](https://example.com)下载傻瓜式工具
+        "### };
+
+        let result = lint_for(raw, "markdown");
+        let lines = result
+            .lines
+            .iter()
+            .map(|l| (l.line, l.new.as_str(), l.severity, l.message.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (
+                    11,
+                    "点击这里",
+                    Severity::Warning,
+                    "Use descriptive link text."
+                ),
+                (
+                    11,
+                    "下载简单易用工具",
+                    Severity::Error,
+                    "Use `简单易用` instead of `傻瓜式`."
+                ),
+            ],
+            lines
+        );
+
+        assert!(format_for(raw, "markdown")
+            .out
+            .ends_with("[点击这里](https://example.com)下载简单易用工具\n"));
+    }
+
     #[test]
     fn test_complex_markdown() {
         let raw = include_str!("../../../tests/fixtures/markdown.raw.md");
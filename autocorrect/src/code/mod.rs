@@ -9,25 +9,37 @@ mod csharp;
 mod css;
 mod dart;
 mod elixir;
+mod fluent;
 mod gettext;
 mod go;
 mod html;
+mod i18n;
 mod java;
 mod javascript;
 mod json;
 mod jupyter;
 mod kotlin;
 mod latex;
+mod lua;
 mod markdown;
 mod objective_c;
+mod org;
 mod php;
 mod python;
+mod rst;
 mod ruby;
 mod rust;
 mod scala;
+mod shell;
 mod sql;
+mod srt;
 mod strings;
+mod stringsdict;
 mod swift;
+mod typst;
+mod vtt;
+mod xcstrings;
+mod xliff;
 mod xml;
 mod yaml;
 mod zig;
@@ -42,25 +54,37 @@ pub use csharp::*;
 pub use css::*;
 pub use dart::*;
 pub use elixir::*;
+pub use fluent::*;
 pub use gettext::*;
 pub use go::*;
 pub use html::*;
+pub use i18n::*;
 pub use java::*;
 pub use javascript::*;
 pub use json::*;
 pub use jupyter::*;
 pub use kotlin::*;
 pub use latex::*;
+pub use lua::*;
 pub use markdown::*;
 pub use objective_c::*;
+pub use org::*;
 pub use php::*;
 pub use python::*;
+pub use rst::*;
 pub use ruby::*;
 pub use rust::*;
 pub use scala::*;
+pub use shell::*;
 pub use sql::*;
+pub use srt::*;
 pub use strings::*;
+pub use stringsdict::*;
 pub use swift::*;
+pub use typst::*;
+pub use vtt::*;
+pub use xcstrings::*;
+pub use xliff::*;
 pub use xml::*;
 pub use yaml::*;
 
@@ -83,38 +107,27 @@ pub use yaml::*;
 /// autocorrect::lint_for(raw, "index.html");
 /// ```
 pub fn lint_for(raw: &str, filename_or_ext: &str) -> LintResult {
+    lint_for_with(LintResult::new(raw), raw, filename_or_ext)
+}
+
+/// Lint a file content with filetype, and collect the toggle directives in it.
+///
+/// Call `LintResult::report_directives` to report the unused, unknown or unclosed directives.
+///
+/// ```ignore
+/// let mut result = autocorrect::lint_for_with_directives(raw, "index.js");
+/// result.report_directives();
+/// ```
+pub fn lint_for_with_directives(raw: &str, filename_or_ext: &str) -> LintResult {
+    let mut result = LintResult::new(raw);
+    result.directives = Some(crate::config::toggle::Directives::default());
+    lint_for_with(result, raw, filename_or_ext)
+}
+
+fn lint_for_with(result: LintResult, raw: &str, filename_or_ext: &str) -> LintResult {
     let mut result = match types::match_filename(filename_or_ext).as_str() {
-        "html" => lint_html(raw),
-        "yaml" => lint_yaml(raw),
-        "sql" => lint_sql(raw),
-        "rust" => lint_rust(raw),
-        "ruby" => lint_ruby(raw),
-        "elixir" => lint_elixir(raw),
-        "go" => lint_go(raw),
-        "javascript" => lint_javascript(raw),
-        "css" => lint_css(raw),
-        "json" => lint_json(raw),
-        "python" => lint_python(raw),
-        "objective_c" => lint_objectivec(raw),
-        "strings" => lint_strings(raw),
-        "csharp" => lint_csharp(raw),
-        "swift" => lint_swift(raw),
-        "java" => lint_java(raw),
-        "scala" => lint_scala(raw),
-        "kotlin" => lint_kotlin(raw),
-        "php" => lint_php(raw),
-        "dart" => lint_dart(raw),
-        "markdown" => lint_markdown(raw),
-        "latex" => lint_latex(raw),
-        "asciidoc" => lint_asciidoc(raw),
-        "gettext" => lint_gettext(raw),
-        "conf" => lint_conf(raw),
-        "c" => lint_c(raw),
-        "xml" => lint_xml(raw),
         "jupyter" => lint_jupyter(raw),
-        "zig" => lint_rust(raw),
-        "text" => lint_markdown(raw),
-        _ => LintResult::new(raw),
+        _ => format_or_lint_for(result, raw, filename_or_ext),
     };
 
     result.filepath = String::from(filename_or_ext);
@@ -141,43 +154,77 @@ pub fn lint_for(raw: &str, filename_or_ext: &str) -> LintResult {
 /// autocorrect::format_for(raw, "index.html");
 /// ```
 pub fn format_for(raw: &str, filename_or_ext: &str) -> FormatResult {
-    let result = match types::match_filename(filename_or_ext).as_str() {
-        "html" => format_html(raw),
-        "yaml" => format_yaml(raw),
-        "sql" => format_sql(raw),
-        "rust" => format_rust(raw),
-        "ruby" => format_ruby(raw),
-        "elixir" => format_elixir(raw),
-        "go" => format_go(raw),
-        "javascript" => format_javascript(raw),
-        "css" => format_css(raw),
-        "json" => format_json(raw),
-        "python" => format_python(raw),
-        "objective_c" => format_objectivec(raw),
-        "strings" => format_strings(raw),
-        "csharp" => format_csharp(raw),
-        "swift" => format_swift(raw),
-        "java" => format_java(raw),
-        "scala" => format_scala(raw),
-        "kotlin" => format_kotlin(raw),
-        "php" => format_php(raw),
-        "dart" => format_dart(raw),
-        "markdown" => format_markdown(raw),
-        "latex" => format_latex(raw),
-        "asciidoc" => format_asciidoc(raw),
-        "gettext" => format_gettext(raw),
-        "conf" => format_conf(raw),
-        "c" => format_c(raw),
-        "xml" => format_xml(raw),
+    match types::match_filename(filename_or_ext).as_str() {
         "jupyter" => format_jupyter(raw),
-        "zig" => format_rust(raw),
-        "text" => format_markdown(raw),
+        _ => format_or_lint_for(FormatResult::new(raw), raw, filename_or_ext),
+    }
+}
+
+/// Is the locale CJK, e.g.: `zh-Hans`, `ja`, `ko_KR`, `yue`.
+pub(crate) fn is_cjk_locale(locale: &str) -> bool {
+    ["zh", "ja", "ko", "yue"].iter().any(|lang| {
+        locale
+            .strip_prefix(lang)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(['-', '_']))
+    })
+}
+
+/// Format or Lint a file content with filetype, into the given results.
+///
+/// The results may carry a toggle state from the parent document, e.g. a codeblock in Markdown.
+pub(crate) fn format_or_lint_for<O: Results>(results: O, raw: &str, filename_or_ext: &str) -> O {
+    let file_type = types::match_filename(filename_or_ext);
+    let mut results = results;
+    results.set_file_type(&file_type);
+    results.set_filepath(filename_or_ext);
+
+    match file_type.as_str() {
+        "html" => format_or_lint_html(results, raw),
+        "yaml" => format_or_lint_yaml(results, raw),
+        "sql" => format_or_lint_sql(results, raw),
+        "rust" => format_or_lint_rust(results, raw),
+        "ruby" => format_or_lint_ruby(results, raw),
+        "lua" => format_or_lint_lua(results, raw),
+        "shell" => format_or_lint_shell(results, raw),
+        "elixir" => format_or_lint_elixir(results, raw),
+        "go" => format_or_lint_go(results, raw),
+        "javascript" => format_or_lint_javascript(results, raw),
+        "css" => format_or_lint_css(results, raw),
+        "json" => format_or_lint_json(results, raw),
+        "python" => format_or_lint_python(results, raw),
+        "objective_c" => format_or_lint_objectivec(results, raw),
+        "strings" => format_or_lint_strings(results, raw),
+        "xcstrings" => format_or_lint_xcstrings(results, raw),
+        "stringsdict" if stringsdict::is_cjk_lproj(filename_or_ext) => {
+            format_or_lint_stringsdict(results, raw)
+        }
+        "csharp" => format_or_lint_csharp(results, raw),
+        "swift" => format_or_lint_swift(results, raw),
+        "java" => format_or_lint_java(results, raw),
+        "scala" => format_or_lint_scala(results, raw),
+        "kotlin" => format_or_lint_kotlin(results, raw),
+        "php" => format_or_lint_php(results, raw),
+        "dart" => format_or_lint_dart(results, raw),
+        "markdown" => format_or_lint_markdown(results, raw),
+        "latex" => format_or_lint_latex(results, raw),
+        "typst" => format_or_lint_typst(results, raw),
+        "asciidoc" => format_or_lint_asciidoc(results, raw),
+        "rst" => format_or_lint_rst(results, raw),
+        "org" => format_or_lint_org(results, raw),
+        "srt" => format_or_lint_srt(results, raw),
+        "vtt" => format_or_lint_vtt(results, raw),
+        "gettext" => format_or_lint_gettext(results, raw),
+        "fluent" => format_or_lint_fluent(results, raw),
+        "i18n" => format_or_lint_i18n(results, raw),
+        "conf" => format_or_lint_conf(results, raw),
+        "c" => format_or_lint_c(results, raw),
+        "xml" => format_or_lint_xml(results, raw),
+        "xliff" => format_or_lint_xliff(results, raw),
+        "zig" => format_or_lint_rust(results, raw),
+        "text" => format_or_lint_markdown(results, raw),
         _ => {
-            let mut result = FormatResult::new(raw);
-            result.out = String::from(raw);
-            result
+            results.ignore(raw);
+            results
         }
-    };
-
-    result
+    }
 }
@@ -0,0 +1,104 @@
+// autocorrect: false
+use super::*;
+
+use autocorrect_derive::GrammarParser;
+use pest::Parser as P;
+use pest_derive::Parser;
+
+#[derive(GrammarParser, Parser)]
+#[grammar = "../grammar/org.pest"]
+struct OrgParser;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_format_org() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        #+TITLE: AutoCorrect设计文档
+        #+OPTIONS: toc:nil 中文
+
+        * TODO [#A] 发布AutoCorrect新版本 :release:v2:
+          :PROPERTIES:
+          :CUSTOM_ID: 发布Release
+          :END:
+
+        AutoCorrect是在MIT许可证下发布,详见[[https://github.com/huacnlee/autocorrect][官方仓库Repo]]和[[file:foo中文.org]]。
+        使用 =autocorrect--fix中文= 和 ~code中文~ 命令。
+
+        - 列表项目1
+        - [X] 已完成Done
+        1. 第一步Step
+
+        #+BEGIN_SRC rust :results output
+        // 这是Rust注释
+        let a = "你好Rust";
+        #+END_SRC
+
+        #+begin_example
+        示例Example
+        #+end_example
+
+        #+BEGIN_QUOTE
+        引用Quote内容
+        #+END_QUOTE
+
+        | 名称Name | 值Value |
+        |----------+---------|
+        | 中文     | English |
+
+        : 固定宽度Fixed
+
+        # 这是注释Comment
+        # autocorrect: false
+        关闭后Disabled
+        "###};
+
+        let expected = indoc! {r###"
+        #+TITLE: AutoCorrect 设计文档
+        #+OPTIONS: toc:nil 中文
+
+        * TODO [#A] 发布 AutoCorrect 新版本 :release:v2:
+          :PROPERTIES:
+          :CUSTOM_ID: 发布Release
+          :END:
+
+        AutoCorrect 是在 MIT 许可证下发布，详见[[https://github.com/huacnlee/autocorrect][官方仓库 Repo]]和[[file:foo中文.org]]。
+        使用 =autocorrect--fix中文= 和 ~code中文~ 命令。
+
+        - 列表项目 1
+        - [X] 已完成 Done
+        1. 第一步 Step
+
+        #+BEGIN_SRC rust :results output
+        // 这是 Rust 注释
+        let a = "你好 Rust";
+        #+END_SRC
+
+        #+begin_example
+        示例Example
+        #+end_example
+
+        #+BEGIN_QUOTE
+        引用 Quote 内容
+        #+END_QUOTE
+
+        | 名称Name | 值Value |
+        |----------+---------|
+        | 中文     | English |
+
+        : 固定宽度Fixed
+
+        # 这是注释 Comment
+        # autocorrect: false
+        关闭后Disabled
+        "###};
+
+        assert_eq!(expected, format_for(example, "org").to_string());
+    }
+}
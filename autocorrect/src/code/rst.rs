@@ -0,0 +1,272 @@
+// autocorrect: false
+use super::*;
+
+use pest::iterators::Pair;
+use pest::Parser as P;
+use pest_derive::Parser;
+use unicode_width::UnicodeWidthStr;
+
+#[derive(Parser)]
+#[grammar = "../grammar/rst.pest"]
+struct RstParser;
+
+#[allow(dead_code)]
+pub fn format_rst(text: &str) -> FormatResult {
+    format_or_lint_rst(FormatResult::new(text), text)
+}
+
+#[allow(dead_code)]
+pub fn lint_rst(text: &str) -> LintResult {
+    format_or_lint_rst(LintResult::new(text), text)
+}
+
+pub(crate) fn format_or_lint_rst<O: Results>(results: O, text: &str) -> O {
+    let pairs = RstParser::parse(Rule::item, text);
+    format_pairs_with_hook(results, pairs, format_section)
+}
+
+/// Format or lint the section title, and extend the adornments (underline / overline)
+/// if the title gets longer than them.
+fn format_section<O: Results>(results: &mut O, pair: &Pair<Rule>) -> bool {
+    if pair.as_rule() != Rule::section {
+        return false;
+    }
+
+    let pairs = pair.clone().into_inner().collect::<Vec<_>>();
+    let Some(title) = pairs.iter().find(|p| p.as_rule() == Rule::section_title) else {
+        return false;
+    };
+
+    let old_width = title.as_str().trim().width();
+    let (new_width, severity) = if results.is_lint() {
+        let mut title_result = LintResult::new(title.as_str());
+        title_result.toggle = results.get_toggle().clone();
+        title_result.set_file_type(results.file_type());
+        format_pair(&mut title_result, title.clone(), |_, _| false);
+
+        let old = title_result
+            .lines
+            .iter()
+            .map(|l| l.old.width())
+            .sum::<usize>();
+        let new = title_result
+            .lines
+            .iter()
+            .map(|l| l.new.width())
+            .sum::<usize>();
+        let severity = if title_result
+            .lines
+            .iter()
+            .any(|l| l.severity == Severity::Error)
+        {
+            Severity::Error
+        } else {
+            Severity::Warning
+        };
+        ((old_width + new).saturating_sub(old), severity)
+    } else {
+        let mut title_result = FormatResult::new(title.as_str());
+        title_result.toggle = results.get_toggle().clone();
+        title_result.set_file_type(results.file_type());
+        format_pair(&mut title_result, title.clone(), |_, _| false);
+
+        (title_result.out.trim().width(), Severity::Pass)
+    };
+
+    for child in pairs {
+        if child.as_rule() != Rule::section_adornment {
+            format_pair(results, child, format_section);
+            continue;
+        }
+
+        let part = child.as_str();
+        let (line, col) = child.line_col();
+        let adornment = part.trim_end();
+        let width = adornment.chars().count();
+
+        // Keep the adornment shorter than the title as is, it is invalid already.
+        if width < old_width || width >= new_width {
+            results.ignore(part);
+            continue;
+        }
+
+        let c = adornment.chars().next().unwrap_or_default();
+        let new_adornment = format!("{adornment}{}", String::from(c).repeat(new_width - width));
+        if results.is_lint() {
+            results.push(LineResult {
+                line,
+                col,
+                old: adornment.to_string(),
+                new: new_adornment,
+                severity,
+                message: String::new(),
+                id: String::new(),
+            });
+            results.ignore(part);
+        } else {
+            results.push(LineResult {
+                line,
+                col,
+                old: part.to_string(),
+                new: format!("{new_adornment}{}", &part[adornment.len()..]),
+                severity: Severity::Pass,
+                message: String::new(),
+                id: String::new(),
+            });
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_format_rst() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        ===============
+        使用AutoCorrect
+        ===============
+
+        简介
+        ====
+
+        AutoCorrect是在MIT许可证下发布,请参考:ref:`install` 和 :doc:`安装指南<install>`。
+        使用 ``autocorrect --fix`` 命令,详见 `官方网站<https://example.com>`_ 和 **加粗Bold** 以及 *强调Text*。
+
+        .. note::
+           这是一个Note提示。
+
+           - 列表项目1
+           - 列表Item 2
+
+        .. code-block:: python
+           :linenos:
+
+           # 这是Python注释
+           print("你好Python")
+
+        示例代码::
+
+            $ autocorrect --fix 中文English
+
+        .. math::
+
+           \text{公式A}
+
+        .. 这是注释Comment
+           第二行
+
+        .. _install: https://example.com
+
+        .. [1] 脚注Footnote内容
+
+        +------------+-------------+
+        | 表格Cell   | 单元格       |
+        +------------+-------------+
+
+        =====  =====
+        标题A  标题B
+        =====  =====
+        值1    值2
+        =====  =====
+
+        :param name: 名称Name
+
+        .. image:: foo.png
+           :alt: 图片Alt
+
+        .. autocorrect: false
+
+        关闭后Disabled
+        "###};
+
+        let expected = indoc! {r###"
+        ================
+        使用 AutoCorrect
+        ================
+
+        简介
+        ====
+
+        AutoCorrect 是在 MIT 许可证下发布，请参考:ref:`install` 和 :doc:`安装指南<install>`。
+        使用 ``autocorrect --fix`` 命令，详见 `官方网站<https://example.com>`_ 和 **加粗 Bold** 以及 *强调 Text*。
+
+        .. note::
+           这是一个 Note 提示。
+
+           - 列表项目 1
+           - 列表 Item 2
+
+        .. code-block:: python
+           :linenos:
+
+           # 这是 Python 注释
+           print("你好 Python")
+
+        示例代码::
+
+            $ autocorrect --fix 中文English
+
+        .. math::
+
+           \text{公式A}
+
+        .. 这是注释 Comment
+           第二行
+
+        .. _install: https://example.com
+
+        .. [1] 脚注 Footnote 内容
+
+        +------------+-------------+
+        | 表格Cell   | 单元格       |
+        +------------+-------------+
+
+        =====  =====
+        标题A  标题B
+        =====  =====
+        值1    值2
+        =====  =====
+
+        :param name: 名称 Name
+
+        .. image:: foo.png
+           :alt: 图片 Alt
+
+        .. autocorrect: false
+
+        关闭后Disabled
+        "###};
+
+        assert_eq!(expected, format_for(example, "rst").to_string());
+    }
+
+    #[test]
+    fn test_lint_rst() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        使用Rust
+        ========
+
+        这是Rust文档。
+        "###};
+
+        let result = lint_for(example, "index.rst");
+        assert_eq!(
+            vec![(1, "使用 Rust"), (2, "========="), (4, "这是 Rust 文档。")],
+            result
+                .lines
+                .iter()
+                .map(|l| (l.line, l.new.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+}
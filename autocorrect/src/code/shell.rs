@@ -0,0 +1,119 @@
+// autocorrect: false
+use super::*;
+
+use autocorrect_derive::GrammarParser;
+use pest::Parser as P;
+use pest_derive::Parser;
+
+#[derive(GrammarParser, Parser)]
+#[grammar = "../grammar/shell.pest"]
+struct ShellParser;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_format_shell() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        #!/usr/bin/env bash
+        # 安装AutoCorrect的脚本
+        set -e
+
+        name="AutoCorrect工具"
+        if [ "$1" = "测试Test" ]; then
+          echo "开始安装${name}到$HOME目录" # 输出Message
+          echo -e "下载完成\n解压$(basename "$file")文件"
+          printf '共%d个文件,耗时%.2f秒\n' "$count" "$secs"
+          printf $'第1步\t完成\n'
+        fi
+
+        echo "It's安装成功" >&2; echo '使用"autocorrect"命令'
+        version=$(curl -s "https://example.com/版本#latest" | grep "#版本")
+        echo "当前版本${version#v}和`uname -s`系统"
+        count=${#files[@]} # 文件数量count
+        cd dist && echo "打包完成Done" | tee log; (echo "子Shell输出")
+        for f in *; do echo "文件$f"; done
+        grep echo "中文Text" && alias say=echo "不是Echo"
+        printf "%-10s个文件%5d行\n" "$name" "$lines"
+        "###};
+
+        let expected = indoc! {r###"
+        #!/usr/bin/env bash
+        # 安装 AutoCorrect 的脚本
+        set -e
+
+        name="AutoCorrect工具"
+        if [ "$1" = "测试Test" ]; then
+          echo "开始安装 ${name} 到 $HOME 目录" # 输出 Message
+          echo -e "下载完成\n解压 $(basename "$file") 文件"
+          printf '共 %d 个文件，耗时 %.2f 秒\n' "$count" "$secs"
+          printf $'第 1 步\t完成\n'
+        fi
+
+        echo "It's 安装成功" >&2; echo '使用"autocorrect"命令'
+        version=$(curl -s "https://example.com/版本#latest" | grep "#版本")
+        echo "当前版本 ${version#v} 和 `uname -s` 系统"
+        count=${#files[@]} # 文件数量 count
+        cd dist && echo "打包完成 Done" | tee log; (echo "子 Shell 输出")
+        for f in *; do echo "文件 $f"; done
+        grep echo "中文Text" && alias say=echo "不是Echo"
+        printf "%-10s个文件%5d行\n" "$name" "$lines"
+        "###};
+
+        assert_eq!(expected, format_for(example, "install.sh").to_string());
+    }
+
+    #[test]
+    fn test_format_shell_heredoc() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        cat <<EOF > README.txt
+        欢迎使用${name},版本$VERSION
+        安装后运行"autocorrect"命令
+        EOF
+        cat <<-'END'
+        	这是第1行
+        	END
+        echo "完成Done"
+        "###};
+
+        let expected = indoc! {r###"
+        cat <<EOF > README.txt
+        欢迎使用 ${name}，版本 $VERSION
+        安装后运行"autocorrect"命令
+        EOF
+        cat <<-'END'
+        	这是第 1 行
+        	END
+        echo "完成 Done"
+        "###};
+
+        assert_eq!(expected, format_for(example, "install.sh").to_string());
+    }
+
+    #[test]
+    fn test_lint_shell() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        # 安装脚本
+        echo "安装Rust"
+        "###};
+
+        let result = lint_for(example, "install.sh");
+        assert_eq!(
+            vec![(2, 7, "安装Rust", "安装 Rust")],
+            result
+                .lines
+                .iter()
+                .map(|l| (l.line, l.col, l.old.as_str(), l.new.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+}
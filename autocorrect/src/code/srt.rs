@@ -0,0 +1,71 @@
+// autocorrect: false
+use super::*;
+
+use autocorrect_derive::GrammarParser;
+use pest::Parser as P;
+use pest_derive::Parser;
+
+#[derive(GrammarParser, Parser)]
+#[grammar = "../grammar/srt.pest"]
+struct SrtParser;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_format_srt() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        1
+        00:01:02,000 --> 00:01:04,500
+        你好World,欢迎来到AutoCorrect!
+
+        2
+        00:01:05,000 --> 00:01:08,000 X1:100 X2:200 Y1:10 Y2:20
+        {\an8}<i>这是Rust教程</i>
+        第2行:内容
+
+        3
+        00:01:09,000 --> 00:01:10,000
+        10:30开始
+        "###};
+
+        let expected = indoc! {r###"
+        1
+        00:01:02,000 --> 00:01:04,500
+        你好 World，欢迎来到 AutoCorrect!
+
+        2
+        00:01:05,000 --> 00:01:08,000 X1:100 X2:200 Y1:10 Y2:20
+        {\an8}<i>这是 Rust 教程</i>
+        第 2 行：内容
+
+        3
+        00:01:09,000 --> 00:01:10,000
+        10:30 开始
+        "###};
+
+        assert_eq!(expected, format_for(example, "srt").to_string());
+    }
+
+    #[test]
+    fn test_lint_srt() {
+        crate::config::setup_test();
+
+        let example = "\u{FEFF}1\r\n00:01:02,000 --> 00:01:04,500\r\n你好World\r\n\r\n2\r\n00:01:05,000 --> 00:01:08,000\r\n正常文本\r\n<i>这是Rust教程</i>\r\n";
+
+        let result = lint_for(example, "zh-CN.srt");
+        assert_eq!(
+            vec![(3, 1, "你好 World"), (8, 4, "这是 Rust 教程")],
+            result
+                .lines
+                .iter()
+                .map(|l| (l.line, l.col, l.new.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+}
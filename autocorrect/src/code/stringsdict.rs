@@ -0,0 +1,107 @@
+// autocorrect: false
+use super::*;
+
+use autocorrect_derive::GrammarParser;
+use pest::Parser as P;
+use pest_derive::Parser;
+
+#[derive(GrammarParser, Parser)]
+#[grammar = "../grammar/stringsdict.pest"]
+struct StringsdictParser;
+
+/// Is the file in the `.lproj` of a CJK locale, e.g.: `zh-Hans.lproj/Localizable.stringsdict`.
+///
+/// The file out of any `.lproj` is also processed, the `Base.lproj` and other locales are not.
+pub(crate) fn is_cjk_lproj(filename: &str) -> bool {
+    let Some(locale) = filename
+        .split(['/', '\\'])
+        .rev()
+        .find_map(|part| part.strip_suffix(".lproj"))
+    else {
+        return true;
+    };
+
+    is_cjk_locale(locale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_format_stringsdict() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+        <plist version="1.0">
+        <dict>
+            <key>%d files in Folder</key>
+            <dict>
+                <!-- 文件数量Comment -->
+                <key>NSStringLocalizedFormatKey</key>
+                <string>共%#@files@在Folder中</string>
+                <key>files</key>
+                <dict>
+                    <key>NSStringFormatSpecTypeKey</key>
+                    <string>NSStringPluralRuleType</string>
+                    <key>NSStringFormatValueTypeKey</key>
+                    <string>d</string>
+                    <key>other</key>
+                    <string>%d个文件,包含Rust &amp; Go</string>
+                </dict>
+            </dict>
+        </dict>
+        </plist>
+        "###};
+
+        let expected = indoc! {r###"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+        <plist version="1.0">
+        <dict>
+            <key>%d files in Folder</key>
+            <dict>
+                <!-- 文件数量Comment -->
+                <key>NSStringLocalizedFormatKey</key>
+                <string>共%#@files@在 Folder 中</string>
+                <key>files</key>
+                <dict>
+                    <key>NSStringFormatSpecTypeKey</key>
+                    <string>NSStringPluralRuleType</string>
+                    <key>NSStringFormatValueTypeKey</key>
+                    <string>d</string>
+                    <key>other</key>
+                    <string>%d个文件，包含 Rust &amp; Go</string>
+                </dict>
+            </dict>
+        </dict>
+        </plist>
+        "###};
+
+        assert_eq!(
+            expected,
+            format_for(example, "zh-Hans.lproj/Localizable.stringsdict").to_string()
+        );
+        assert_eq!(
+            example,
+            format_for(example, "en.lproj/Localizable.stringsdict").to_string()
+        );
+    }
+
+    #[test]
+    fn test_is_cjk_lproj() {
+        assert!(is_cjk_lproj("stringsdict"));
+        assert!(is_cjk_lproj("Localizable.stringsdict"));
+        assert!(is_cjk_lproj("App/zh-Hans.lproj/Localizable.stringsdict"));
+        assert!(is_cjk_lproj("App/ja.lproj/Localizable.stringsdict"));
+        assert!(is_cjk_lproj("App/ko.lproj/Localizable.stringsdict"));
+        assert!(is_cjk_lproj("App/zh_TW.lproj/Localizable.stringsdict"));
+        assert!(!is_cjk_lproj("App/en.lproj/Localizable.stringsdict"));
+        assert!(!is_cjk_lproj("App/Base.lproj/Localizable.stringsdict"));
+        assert!(!is_cjk_lproj("App/kok.lproj/Localizable.stringsdict"));
+    }
+}
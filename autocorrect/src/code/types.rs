@@ -5,7 +5,7 @@ pub fn match_filename(filename_or_ext: &str) -> String {
     let ext = get_file_extension(filename_or_ext);
 
     // Return file type by config
-    if let Some(file_type) = Config::current().get_file_type(&ext) {
+    if let Some(file_type) = Config::effective().get_file_type(&ext) {
         return file_type.into();
     }
 
@@ -14,7 +14,7 @@ pub fn match_filename(filename_or_ext: &str) -> String {
 
 // dectermines file_type is support
 pub fn is_support_type(filename_or_ext: &str) -> bool {
-    Config::current().get_file_type(filename_or_ext).is_some()
+    Config::effective().get_file_type(filename_or_ext).is_some()
 }
 
 // get file extension from filepath, return filename if not has exit
@@ -26,6 +26,11 @@ pub fn get_file_extension(filename: &str) -> String {
     }
 
     let filename = filename.split('/').last().unwrap().to_string();
+    // The file name in config, e.g.: `zh-CN.yml: i18n`
+    if is_support_type(&filename) {
+        return filename;
+    }
+
     let path_parts: Vec<&str> = filename.split('.').collect();
     let mut ext: String = path_parts.last().unwrap().to_string();
 
@@ -99,6 +104,14 @@ mod tests {
         assert_eq!("conf".to_owned(), match_filename("app.cfg"));
 
         assert_eq!("strings".to_owned(), match_filename("app.strings"));
+        assert_eq!(
+            "xcstrings".to_owned(),
+            match_filename("Localizable.xcstrings")
+        );
+        assert_eq!(
+            "stringsdict".to_owned(),
+            match_filename("zh-Hans.lproj/Localizable.stringsdict")
+        );
 
         assert_eq!("python".to_owned(), match_filename("app.py"));
 
@@ -108,24 +121,53 @@ mod tests {
         assert_eq!("kotlin".to_owned(), match_filename("app.kt"));
 
         assert_eq!("xml".to_owned(), match_filename("zh-CN.xml"));
+        assert_eq!("xliff".to_owned(), match_filename("messages.xlf"));
+        assert_eq!("xliff".to_owned(), match_filename("messages.xliff"));
 
         assert_eq!("asciidoc".to_owned(), match_filename("bar.adoc"));
         assert_eq!("asciidoc".to_owned(), match_filename("bar.asc"));
 
+        assert_eq!("rst".to_owned(), match_filename("index.rst"));
+        assert_eq!("rst".to_owned(), match_filename("index.rest"));
+
+        assert_eq!("org".to_owned(), match_filename("notes.org"));
+
+        assert_eq!("srt".to_owned(), match_filename("zh-CN.srt"));
+        assert_eq!("vtt".to_owned(), match_filename("zh-CN.vtt"));
+
         assert_eq!("java".to_owned(), match_filename("bar.proto"));
 
         assert_eq!("latex".to_owned(), match_filename("bar.tex"));
+        assert_eq!("typst".to_owned(), match_filename("report.typ"));
 
         assert_eq!("gettext".to_owned(), match_filename("bar.pot"));
         assert_eq!("gettext".to_owned(), match_filename("bar.po"));
 
+        assert_eq!("fluent".to_owned(), match_filename("main.ftl"));
+        assert_eq!("i18n".to_owned(), match_filename("app_zh.arb"));
+
         // Follow file type in .autocorrecrrc.default
         assert_eq!("ruby".to_owned(), match_filename("Gemfile"));
         assert_eq!("ruby".to_owned(), match_filename("Rakefile"));
         assert_eq!("ruby".to_owned(), match_filename("Profile"));
         assert_eq!("ruby".to_owned(), match_filename("foo.gemspec"));
 
+        assert_eq!("lua".to_owned(), match_filename("main.lua"));
+
+        assert_eq!("shell".to_owned(), match_filename("install.sh"));
+        assert_eq!("shell".to_owned(), match_filename("install.bash"));
+        assert_eq!("shell".to_owned(), match_filename("install.zsh"));
+
         assert_eq!("jupyter".to_owned(), match_filename("./foo/bar.jupyter"));
         assert_eq!("jupyter".to_owned(), match_filename("./foo/bar.ipynb"));
+
+        let config = crate::Config::from_str("fileTypes:\n  zh-CN.yml: i18n").unwrap();
+        let last_config = crate::config::set_document_config(Some(&config)).unwrap();
+        assert_eq!(
+            "i18n".to_owned(),
+            match_filename("config/locales/zh-CN.yml")
+        );
+        assert_eq!("yaml".to_owned(), match_filename("config/locales/en.yml"));
+        crate::config::restore_document_config(last_config);
     }
 }
@@ -0,0 +1,104 @@
+// autocorrect: false
+use super::*;
+
+use autocorrect_derive::GrammarParser;
+use pest::Parser as P;
+use pest_derive::Parser;
+
+#[derive(GrammarParser, Parser)]
+#[grammar = "../grammar/typst.pest"]
+struct TypstParser;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_format_typst() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        #set text(lang: "zh", font: "思源宋体Noto")
+        #let title = "报告Title"
+
+        = 简介Introduction <intro>
+        // 这是注释comment
+        这是一个Typst文档,参见@intro和https://typst.app/docs页面。
+        - 第1项
+        + 第2步
+        / 术语Term: 描述Description
+
+        公式$x^2+y^2$在第3页,使用`raw代码`和\#符号。
+        #text(fill: red, "警告Warning")[这是Text]
+        #link("https://typst.app")[Typst官网]
+        #text(font: "思源宋体Noto", size: 12pt, "正文Body" )
+        #link("https://zh.wikipedia.org/wiki/中文Wiki,百科", "维基Wiki")
+        #link(
+          "https://typst.app/docs/中文Docs",
+        )[文档Docs]
+        #strong[加粗Bold] #align(center)[居中Center]
+        #{
+          let x = "代码Code"
+        }
+
+        ```rust
+        // 这是Rust注释
+        fn main() {}
+        ```
+        "###};
+
+        let expected = indoc! {r###"
+        #set text(lang: "zh", font: "思源宋体Noto")
+        #let title = "报告Title"
+
+        = 简介 Introduction <intro>
+        // 这是注释 comment
+        这是一个 Typst 文档，参见@intro和https://typst.app/docs页面。
+        - 第 1 项
+        + 第 2 步
+        / 术语 Term: 描述 Description
+
+        公式$x^2+y^2$在第 3 页，使用`raw代码`和\#符号。
+        #text(fill: red, "警告 Warning")[这是 Text]
+        #link("https://typst.app")[Typst 官网]
+        #text(font: "思源宋体Noto", size: 12pt, "正文 Body" )
+        #link("https://zh.wikipedia.org/wiki/中文Wiki,百科", "维基 Wiki")
+        #link(
+          "https://typst.app/docs/中文Docs",
+        )[文档 Docs]
+        #strong[加粗 Bold] #align(center)[居中 Center]
+        #{
+          let x = "代码Code"
+        }
+
+        ```rust
+        // 这是 Rust 注释
+        fn main() {}
+        ```
+        "###};
+
+        assert_eq!(expected, format_for(example, "report.typ").to_string());
+    }
+
+    #[test]
+    fn test_lint_typst() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        = 标题
+        第1段文字
+        "###};
+
+        let result = lint_for(example, "report.typ");
+        assert_eq!(
+            vec![(2, 1, "第1段文字", "第 1 段文字")],
+            result
+                .lines
+                .iter()
+                .map(|l| (l.line, l.col, l.old.as_str(), l.new.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+}
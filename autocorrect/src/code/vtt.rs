@@ -0,0 +1,90 @@
+// autocorrect: false
+use super::*;
+
+use autocorrect_derive::GrammarParser;
+use pest::Parser as P;
+use pest_derive::Parser;
+
+#[derive(GrammarParser, Parser)]
+#[grammar = "../grammar/vtt.pest"]
+struct VttParser;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_format_vtt() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        WEBVTT - 中文字幕Subtitle
+        Kind: captions
+
+        NOTE 这是注释Comment,
+        不会被处理
+
+        STYLE
+        ::cue { color: 红色; }
+
+        intro介绍
+        00:01.000 --> 00:04.000 position:10%,line-left align:start
+        <v 主持人>你好World,欢迎!</v>
+
+        00:00:05.000 --> 00:00:08.000
+        这是<c.yellow>Rust</c>教程<00:00:06.500>第2部分
+        "###};
+
+        let expected = indoc! {r###"
+        WEBVTT - 中文字幕Subtitle
+        Kind: captions
+
+        NOTE 这是注释Comment,
+        不会被处理
+
+        STYLE
+        ::cue { color: 红色; }
+
+        intro介绍
+        00:01.000 --> 00:04.000 position:10%,line-left align:start
+        <v 主持人>你好 World，欢迎！</v>
+
+        00:00:05.000 --> 00:00:08.000
+        这是<c.yellow>Rust</c>教程<00:00:06.500>第 2 部分
+        "###};
+
+        assert_eq!(expected, format_for(example, "vtt").to_string());
+    }
+
+    #[test]
+    fn test_format_vtt_with_note_in_scope() {
+        crate::config::setup_test();
+
+        let config = crate::Config::from_str("scopes:\n  vtt: [text, comment]").unwrap();
+        let last_config = crate::config::set_document_config(Some(&config)).unwrap();
+
+        let example = indoc! {r###"
+        WEBVTT
+
+        NOTE 这是注释Comment
+
+        00:01.000 --> 00:04.000
+        你好World
+        "###};
+
+        let expected = indoc! {r###"
+        WEBVTT
+
+        NOTE 这是注释 Comment
+
+        00:01.000 --> 00:04.000
+        你好 World
+        "###};
+
+        assert_eq!(expected, format_for(example, "vtt").to_string());
+
+        crate::config::restore_document_config(last_config);
+    }
+}
@@ -0,0 +1,118 @@
+// autocorrect: false
+use super::*;
+
+use autocorrect_derive::GrammarParser;
+use pest::Parser as P;
+use pest_derive::Parser;
+
+#[derive(GrammarParser, Parser)]
+#[grammar = "../grammar/xcstrings.pest"]
+struct XcstringsParser;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_format_xcstrings() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        {
+          "sourceLanguage" : "en",
+          "strings" : {
+            "Hello %@, you have %lld messages" : {
+              "comment" : "首页的Greeting",
+              "localizations" : {
+                "en" : {
+                  "stringUnit" : {
+                    "state" : "translated",
+                    "value" : "Hello %@, you have %lld messages"
+                  }
+                },
+                "zh-Hans" : {
+                  "stringUnit" : {
+                    "state" : "translated",
+                    "value" : "你好%@,你有%lld条新Message\n请查看"
+                  }
+                },
+                "ja" : {
+                  "variations" : {
+                    "plural" : {
+                      "other" : {
+                        "stringUnit" : {
+                          "state" : "translated",
+                          "value" : "%1$@さん、%2$lld件のMessageがあります"
+                        }
+                      }
+                    }
+                  }
+                },
+                "fr" : {
+                  "stringUnit" : {
+                    "state" : "translated",
+                    "value" : "Bonjour %@ , 中文Test"
+                  }
+                }
+              }
+            },
+            "使用Rust" : {
+              "extractionState" : "manual"
+            }
+          },
+          "version" : "1.0"
+        }
+        "###};
+
+        let expected = indoc! {r###"
+        {
+          "sourceLanguage" : "en",
+          "strings" : {
+            "Hello %@, you have %lld messages" : {
+              "comment" : "首页的Greeting",
+              "localizations" : {
+                "en" : {
+                  "stringUnit" : {
+                    "state" : "translated",
+                    "value" : "Hello %@, you have %lld messages"
+                  }
+                },
+                "zh-Hans" : {
+                  "stringUnit" : {
+                    "state" : "translated",
+                    "value" : "你好%@,你有%lld条新 Message\n请查看"
+                  }
+                },
+                "ja" : {
+                  "variations" : {
+                    "plural" : {
+                      "other" : {
+                        "stringUnit" : {
+                          "state" : "translated",
+                          "value" : "%1$@さん、%2$lld件の Message があります"
+                        }
+                      }
+                    }
+                  }
+                },
+                "fr" : {
+                  "stringUnit" : {
+                    "state" : "translated",
+                    "value" : "Bonjour %@ , 中文Test"
+                  }
+                }
+              }
+            },
+            "使用Rust" : {
+              "extractionState" : "manual"
+            }
+          },
+          "version" : "1.0"
+        }
+        "###};
+
+        assert_eq!(expected, format_for(example, "xcstrings").to_string());
+    }
+}
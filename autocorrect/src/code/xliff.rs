@@ -0,0 +1,287 @@
+// autocorrect: false
+use super::*;
+
+use pest::iterators::Pair;
+use pest::Parser as P;
+use pest_derive::Parser;
+use regex::Regex;
+
+#[derive(Parser)]
+#[grammar = "../grammar/xliff.pest"]
+struct XliffParser;
+
+lazy_static! {
+    static ref TARGET_LANGUAGE_RE: Regex =
+        regexp!(r#"\s(?:target-language|trgLang)\s*=\s*["']([^"']*)["']"#);
+}
+
+#[allow(dead_code)]
+pub fn format_xliff(text: &str) -> FormatResult {
+    format_or_lint_xliff(FormatResult::new(text), text)
+}
+
+#[allow(dead_code)]
+pub fn lint_xliff(text: &str) -> LintResult {
+    format_or_lint_xliff(LintResult::new(text), text)
+}
+
+pub(crate) fn format_or_lint_xliff<O: Results>(results: O, text: &str) -> O {
+    let pairs = XliffParser::parse(Rule::item, text);
+    format_pairs_with_hook(results, pairs, format_cjk_target)
+}
+
+/// Only format the units with CJK target language, it is decided by each `<file>`,
+/// e.g.: `target-language="zh-CN"` in 1.2, or `trgLang="ja"` of the root in 2.x.
+///
+/// The units without the target language are also processed.
+fn format_cjk_target<O: Results>(results: &mut O, pair: &Pair<Rule>) -> bool {
+    if pair.as_rule() != Rule::xliff {
+        return false;
+    }
+
+    let mut root_lang = None;
+    for child in pair.clone().into_inner() {
+        let lang = match child.as_rule() {
+            Rule::xliff_open => {
+                root_lang = target_language(child.as_str());
+                None
+            }
+            Rule::file => child
+                .clone()
+                .into_inner()
+                .next()
+                .and_then(|file_open| target_language(file_open.as_str()))
+                .or(root_lang),
+            _ => root_lang,
+        };
+
+        if lang.is_none_or(is_cjk_locale) {
+            format_pair(results, child, |_, _| false);
+        } else {
+            results.ignore(child.as_str());
+        }
+    }
+
+    true
+}
+
+/// The target language in the open tag, e.g.: `<file target-language="zh-CN">`, `<xliff trgLang="ja">`
+fn target_language(tag: &str) -> Option<&str> {
+    TARGET_LANGUAGE_RE
+        .captures(tag)
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_format_xliff_1_2() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+          <file source-language="en" target-language="zh-CN" datatype="plaintext" original="messages">
+            <body>
+              <trans-unit id="welcome" approved="yes">
+                <source>Welcome<x id="1"/>to App</source>
+                <target state="translated">欢迎<x id="1"/>使用App,谢谢</target>
+                <note>说明Note</note>
+              </trans-unit>
+              <trans-unit id="link">
+                <source>Click <g id="1">here</g> to read %s</source>
+                <target>点击<g id="1">这里Here</g>阅读<ph id="2">%s文档</ph></target>
+              </trans-unit>
+              <trans-unit id="empty">
+                <source>Empty</source>
+                <target/>
+              </trans-unit>
+            </body>
+          </file>
+        </xliff>
+        "###};
+
+        let expected = indoc! {r###"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+          <file source-language="en" target-language="zh-CN" datatype="plaintext" original="messages">
+            <body>
+              <trans-unit id="welcome" approved="yes">
+                <source>Welcome<x id="1"/>to App</source>
+                <target state="translated">欢迎<x id="1"/>使用 App，谢谢</target>
+                <note>说明Note</note>
+              </trans-unit>
+              <trans-unit id="link">
+                <source>Click <g id="1">here</g> to read %s</source>
+                <target>点击<g id="1">这里 Here</g>阅读<ph id="2">%s文档</ph></target>
+              </trans-unit>
+              <trans-unit id="empty">
+                <source>Empty</source>
+                <target/>
+              </trans-unit>
+            </body>
+          </file>
+        </xliff>
+        "###};
+
+        assert_eq!(expected, format_for(example, "messages.xlf").to_string());
+    }
+
+    #[test]
+    fn test_format_xliff_2() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        <xliff xmlns="urn:oasis:names:tc:xliff:document:2.0" version="2.0" srcLang="en" trgLang="ja">
+          <file id="f1">
+            <unit id="u1">
+              <segment>
+                <source>Hello <pc id="1">World</pc></source>
+                <target>こんにちは<pc id="1">World世界</pc><ph id="2"/></target>
+              </segment>
+            </unit>
+          </file>
+        </xliff>
+        "###};
+
+        let expected = indoc! {r###"
+        <xliff xmlns="urn:oasis:names:tc:xliff:document:2.0" version="2.0" srcLang="en" trgLang="ja">
+          <file id="f1">
+            <unit id="u1">
+              <segment>
+                <source>Hello <pc id="1">World</pc></source>
+                <target>こんにちは<pc id="1">World 世界</pc><ph id="2"/></target>
+              </segment>
+            </unit>
+          </file>
+        </xliff>
+        "###};
+
+        assert_eq!(expected, format_for(example, "messages.xliff").to_string());
+
+        let example = example.replace(r#"trgLang="ja""#, r#"trgLang="fr""#);
+        assert_eq!(example, format_for(&example, "messages.xliff").to_string());
+    }
+
+    #[test]
+    fn test_lint_xliff() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        <xliff version="1.2">
+          <file target-language="zh-Hans">
+            <body>
+              <trans-unit id="title">
+                <source>Title</source>
+                <target>标题Title</target>
+              </trans-unit>
+              <trans-unit id="messages">
+                <source>You have 1 message</source>
+                <target>你有1条消息</target>
+              </trans-unit>
+            </body>
+          </file>
+        </xliff>
+        "###};
+
+        let result = lint_for(example, "messages.xlf");
+        assert_eq!(
+            vec![
+                (6, 17, "title", "标题Title", "标题 Title"),
+                (10, 17, "messages", "你有1条消息", "你有 1 条消息")
+            ],
+            result
+                .lines
+                .iter()
+                .map(|l| (l.line, l.col, l.id.as_str(), l.old.as_str(), l.new.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_format_xliff_by_file() {
+        crate::config::setup_test();
+
+        let example = indoc! {r###"
+        <xliff version="1.2">
+          <file target-language="fr-FR">
+            <body>
+              <trans-unit id="fr">
+                <target>Bonjour你好</target>
+              </trans-unit>
+            </body>
+          </file>
+          <file target-language="zh-CN">
+            <body>
+              <trans-unit id="zh">
+                <target>你好Hello</target>
+              </trans-unit>
+            </body>
+          </file>
+          <file target-language="en">
+            <body>
+              <trans-unit id="en">
+                <target>Hello你好</target>
+              </trans-unit>
+            </body>
+          </file>
+        </xliff>
+        "###};
+
+        let expected = indoc! {r###"
+        <xliff version="1.2">
+          <file target-language="fr-FR">
+            <body>
+              <trans-unit id="fr">
+                <target>Bonjour你好</target>
+              </trans-unit>
+            </body>
+          </file>
+          <file target-language="zh-CN">
+            <body>
+              <trans-unit id="zh">
+                <target>你好 Hello</target>
+              </trans-unit>
+            </body>
+          </file>
+          <file target-language="en">
+            <body>
+              <trans-unit id="en">
+                <target>Hello你好</target>
+              </trans-unit>
+            </body>
+          </file>
+        </xliff>
+        "###};
+
+        assert_eq!(expected, format_for(example, "messages.xlf").to_string());
+
+        let result = lint_for(example, "messages.xlf");
+        assert_eq!(
+            vec!["zh"],
+            result
+                .lines
+                .iter()
+                .map(|l| l.id.as_str())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_target_language() {
+        assert_eq!(
+            Some("zh-CN"),
+            target_language(r#"<file target-language="zh-CN">"#)
+        );
+        assert_eq!(
+            Some("ko"),
+            target_language(r#"<xliff srcLang="en" trgLang='ko'>"#)
+        );
+        assert_eq!(None, target_language(r#"<xliff version="1.2">"#));
+    }
+}
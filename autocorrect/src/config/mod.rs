@@ -1,12 +1,21 @@
+mod scope;
 mod severity;
+mod space_policy;
 mod spellcheck;
+mod terms;
 pub mod toggle;
+mod width;
 
+pub use scope::*;
 pub use severity::*;
+pub use space_policy::*;
 pub use spellcheck::*;
+pub use terms::*;
+pub use width::*;
 
 use serde::{Deserialize, Serialize};
 use std::{
+    cell::RefCell,
     collections::HashMap,
     fs,
     path::Path,
@@ -23,6 +32,11 @@ lazy_static! {
     static ref CURRENT_CONFIG: RwLock<Config> = RwLock::new(Config::from_str(&CONFIG_STR).unwrap());
 }
 
+thread_local! {
+    // The config of the document in processing, e.g.: the `autocorrect:` key in Markdown front matter.
+    static DOCUMENT_CONFIG: RefCell<Option<Arc<Config>>> = const { RefCell::new(None) };
+}
+
 pub trait ConfigFileTypes {
     fn get_ext(&self, ext: &str) -> Option<&str>;
 }
@@ -58,6 +72,18 @@ pub struct Config {
     // Addition file types map, high priority than default
     #[serde(default)]
     pub file_types: HashMap<String, String>,
+    // Prohibited or discouraged terms
+    #[serde(default)]
+    pub terms: Vec<TermConfig>,
+    // The node kinds to process for each file type, e.g.: `javascript: [comment]`
+    #[serde(default)]
+    pub scopes: HashMap<String, ScopeConfig>,
+    // Extend or override the width conversion tables
+    #[serde(default)]
+    pub width_maps: WidthMapsConfig,
+    // Add or remove the spaces between CJK and English, default is `add`
+    #[serde(default)]
+    pub space_policy: Option<SpacePolicyConfig>,
 }
 
 impl Default for Config {
@@ -67,6 +93,10 @@ impl Default for Config {
             text_rules: HashMap::new(),
             spellcheck: SpellcheckConfig::default(),
             file_types: HashMap::new(),
+            terms: vec![],
+            scopes: HashMap::new(),
+            width_maps: WidthMapsConfig::default(),
+            space_policy: None,
         }
     }
 }
@@ -77,19 +107,96 @@ pub fn load_file(config_file: &str) -> Result<Config, Error> {
         return Ok(Config::default());
     }
 
-    let config_str = fs::read_to_string(Path::new(config_file))?;
+    let config_str = fs::read_to_string(config_path)?;
+    let mut config: Config = Config::from_str(&config_str)?;
 
-    load(&config_str)
+    // Resolve the relative paths from the config file location.
+    let base_dir = config_path.parent().unwrap_or(Path::new("."));
+    config.spellcheck.load_dicts(base_dir)?;
+
+    load_config(&config)
 }
 
 pub fn load(config_str: &str) -> Result<Config, Error> {
-    let config: Config = Config::from_str(config_str)?;
+    let mut config: Config = Config::from_str(config_str)?;
+    config.spellcheck.load_dicts(Path::new("."))?;
 
-    let new_config: Config = CURRENT_CONFIG.write().unwrap().merge(&config)?;
+    load_config(&config)
+}
+
+fn load_config(config: &Config) -> Result<Config, Error> {
+    let new_config: Config = CURRENT_CONFIG.write().unwrap().merge(config)?;
 
     Ok(new_config)
 }
 
+/// The document config in front matter, `autocorrect: false` to disable the document.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum DocumentConfig {
+    Enable(bool),
+    Config(Box<Config>),
+}
+
+#[derive(Deserialize)]
+struct FrontMatter {
+    autocorrect: DocumentConfig,
+}
+
+/// Parse the `autocorrect:` key in the front matter, e.g.:
+///
+/// ```yml
+/// autocorrect:
+///   rules:
+///     fullwidth: off
+///   spellcheck:
+///     words:
+///       - Foo
+/// ```
+///
+/// The relative paths of `spellcheck.dicts` are resolved from the `base_dir`, it is the directory of the document.
+pub fn parse_front_matter(yaml_str: &str, base_dir: &Path) -> Result<DocumentConfig, Error> {
+    let front_matter: FrontMatter = match serde_yaml::from_str(yaml_str) {
+        Ok(front_matter) => front_matter,
+        Err(err) => return Err(format!("Front matter config parse error: {err}").into()),
+    };
+
+    let mut document_config = front_matter.autocorrect;
+    if let DocumentConfig::Config(config) = &mut document_config {
+        config.spellcheck.check_presets()?;
+        config.spellcheck.load_dicts(base_dir)?;
+        config.width_maps.check()?;
+        config.prepare_terms()?;
+        config.prepare();
+    }
+
+    Ok(document_config)
+}
+
+/// Set the config for the document in processing on current thread, return the previous one.
+///
+/// The config will be merged with the config in effect.
+pub(crate) fn set_document_config(config: Option<&Config>) -> Result<Option<Arc<Config>>, Error> {
+    let new_config = match config {
+        Some(config) => {
+            let mut new_config = (*Config::effective()).clone();
+            Some(Arc::new(new_config.merge(config)?))
+        }
+        None => None,
+    };
+
+    Ok(restore_document_config(new_config))
+}
+
+pub(crate) fn document_config() -> Option<Arc<Config>> {
+    DOCUMENT_CONFIG.with(|current| current.borrow().clone())
+}
+
+/// Restore the document config with the value returned by `set_document_config`.
+pub(crate) fn restore_document_config(config: Option<Arc<Config>>) -> Option<Arc<Config>> {
+    DOCUMENT_CONFIG.with(|current| current.replace(config))
+}
+
 #[derive(Debug, Clone)]
 pub struct Error {
     message: String,
@@ -131,15 +238,61 @@ impl From<std::string::String> for Error {
     }
 }
 
+/// The config in effect, see `Config::effective`.
+pub(crate) enum EffectiveConfig {
+    Document(Arc<Config>),
+    Global(RwLockReadGuard<'static, Config>),
+}
+
+impl std::ops::Deref for EffectiveConfig {
+    type Target = Config;
+
+    fn deref(&self) -> &Config {
+        match self {
+            EffectiveConfig::Document(config) => config,
+            EffectiveConfig::Global(config) => config,
+        }
+    }
+}
+
 impl Config {
+    /// The global config, it does not include the document config in front matter.
+    // Keep the return type for compatibility.
+    #[allow(clippy::arc_with_non_send_sync)]
     pub fn current() -> Arc<RwLockReadGuard<'static, Config>> {
         Arc::new(CURRENT_CONFIG.read().unwrap())
     }
 
+    /// The config in effect for the document in processing on current thread,
+    /// fallback to the global config if the document has no config.
+    pub(crate) fn effective() -> EffectiveConfig {
+        match document_config() {
+            Some(config) => EffectiveConfig::Document(config),
+            None => EffectiveConfig::Global(CURRENT_CONFIG.read().unwrap()),
+        }
+    }
+
     pub fn get_file_type(&self, ext: &str) -> Option<&str> {
         self.file_types.get_ext(ext)
     }
 
+    /// Return the space policy of the rule, see `spacePolicy`.
+    pub fn space_policy(&self, rule_name: &str) -> SpacePolicy {
+        self.space_policy
+            .as_ref()
+            .map(|policy| policy.get(rule_name))
+            .unwrap_or_default()
+    }
+
+    /// Return the rules out of scope for the node kind in the file type,
+    /// `None` means the node is out of scope for all rules.
+    pub fn scope_disable_rules(&self, file_type: &str, kind: ScopeKind) -> Option<Vec<String>> {
+        match self.scopes.get(file_type) {
+            Some(scope) => scope.disable_rules(kind),
+            None => Some(vec![]),
+        }
+    }
+
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<Self, Error> {
         let mut config: Config = match serde_any::from_str_any(s) {
@@ -147,6 +300,9 @@ impl Config {
             Err(err) => return Err(format!("Config::from_str parse error: {err:?}").into()),
         };
 
+        config.spellcheck.check_presets()?;
+        config.width_maps.check()?;
+        config.prepare_terms()?;
         config.prepare();
 
         Ok(config)
@@ -154,6 +310,29 @@ impl Config {
 
     pub fn prepare(&mut self) {
         self.spellcheck.prepare();
+        self.width_maps.prepare();
+    }
+
+    fn prepare_terms(&mut self) -> Result<(), Error> {
+        for term in self.terms.iter_mut() {
+            term.prepare()?;
+        }
+
+        Ok(())
+    }
+
+    /// The config to override in the files of the file type, e.g.: `widthMaps.fileTypes`.
+    pub(crate) fn file_type_config(&self, file_type: &str) -> Option<Config> {
+        let width_maps = self.width_maps.file_types.get(file_type)?;
+
+        Some(Config {
+            width_maps: WidthMapsConfig {
+                halfwidth: width_maps.halfwidth.clone(),
+                fullwidth: width_maps.fullwidth.clone(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
     }
 
     pub fn merge(&mut self, config: &Config) -> Result<Config, Error> {
@@ -182,6 +361,42 @@ impl Config {
             .chain(config.spellcheck.words.iter())
             .cloned()
             .collect();
+        self.spellcheck.cjk_words = self
+            .spellcheck
+            .cjk_words
+            .iter()
+            .chain(config.spellcheck.cjk_words.iter())
+            .cloned()
+            .collect();
+
+        for dict in config.spellcheck.dicts.iter() {
+            if !self.spellcheck.dicts.contains(dict) {
+                self.spellcheck.dicts.push(dict.clone());
+            }
+        }
+        config.spellcheck.dict_words.iter().for_each(|(k, v)| {
+            self.spellcheck
+                .dict_words
+                .insert(k.to_owned(), v.to_owned());
+        });
+
+        self.terms.extend(config.terms.iter().cloned());
+
+        config.scopes.iter().for_each(|(k, v)| {
+            self.scopes.insert(k.to_owned(), v.to_owned());
+        });
+
+        self.width_maps.merge(&config.width_maps);
+
+        if let Some(space_policy) = &config.space_policy {
+            self.space_policy = Some(space_policy.clone());
+        }
+
+        for preset in config.spellcheck.presets.iter() {
+            if !self.spellcheck.presets.contains(preset) {
+                self.spellcheck.presets.push(preset.clone());
+            }
+        }
 
         self.prepare();
 
@@ -324,6 +539,130 @@ mod tests {
         assert_eq!(Vec::<String>::new(), config.spellcheck.words);
     }
 
+    #[test]
+    fn test_spellcheck_dicts() {
+        let mut config = Config::from_str(indoc! {r#"
+        spellcheck:
+          words:
+            - iOS
+          dicts:
+            - ./dict/brands.txt
+        "#})
+        .unwrap();
+
+        let base_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        config.spellcheck.load_dicts(&base_dir).unwrap();
+        assert_eq!(
+            vec![base_dir
+                .join("./dict/brands.txt")
+                .to_string_lossy()
+                .to_string()],
+            config.spellcheck.dicts
+        );
+
+        config.prepare();
+        let word_map = &config.spellcheck.word_map;
+        assert_eq!(5, word_map.len());
+        assert_eq!(Some(&"iOS".to_owned()), word_map.get("ios"));
+        assert_eq!(Some(&"GitHub".to_owned()), word_map.get("github"));
+        assert_eq!(Some(&"Node.js".to_owned()), word_map.get("nodejs"));
+        assert_eq!(Some(&"TypeScript".to_owned()), word_map.get("typescript"));
+        assert_eq!(vec!["iOS"], config.spellcheck.words);
+
+        // The words are loaded once, prepare does not read the dict files again.
+        let dict_path = std::env::temp_dir().join("autocorrect-test-dict.txt");
+        fs::write(&dict_path, "Foo\n# comment\nbar = Bar\n").unwrap();
+        let mut config = Config::from_str(&format!(
+            "spellcheck:\n  dicts:\n    - {}",
+            dict_path.display()
+        ))
+        .unwrap();
+        config.spellcheck.load_dicts(&base_dir).unwrap();
+        fs::remove_file(&dict_path).unwrap();
+        config.prepare();
+        assert_eq!(2, config.spellcheck.word_map.len());
+        assert_eq!(
+            Some(&"Bar".to_owned()),
+            config.spellcheck.word_map.get("bar")
+        );
+
+        let mut config = Config::from_str("spellcheck:\n  dicts:\n    - ./not-exist.txt").unwrap();
+        let err = config.spellcheck.load_dicts(&base_dir).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Spellcheck dict file not found:"));
+    }
+
+    #[test]
+    fn test_spellcheck_presets() {
+        let mut config = Config::from_str(indoc! {r#"
+        spellcheck:
+          words:
+            - wifi = WiFi
+          presets:
+            - tech-brands
+            - apple
+        "#})
+        .unwrap();
+        config.prepare();
+
+        let word_map = &config.spellcheck.word_map;
+        assert_eq!(Some(&"GitHub".to_owned()), word_map.get("github"));
+        assert_eq!(Some(&"macOS".to_owned()), word_map.get("macos"));
+        assert_eq!(Some(&"App Store".to_owned()), word_map.get("appstore"));
+        assert_eq!(None, word_map.get("kubernetes"));
+        // The words has higher priority than presets
+        assert_eq!(Some(&"WiFi".to_owned()), word_map.get("wifi"));
+
+        let mut other =
+            Config::from_str("spellcheck:\n  presets:\n    - cloud\n    - apple").unwrap();
+        other.merge(&config).unwrap();
+        assert_eq!(
+            vec!["cloud", "apple", "tech-brands"],
+            other.spellcheck.presets
+        );
+        assert_eq!(
+            Some(&"Kubernetes".to_owned()),
+            other.spellcheck.word_map.get("kubernetes")
+        );
+
+        let err = Config::from_str("spellcheck:\n  presets:\n    - foo").unwrap_err();
+        assert_eq!(
+            "Unknown spellcheck preset: foo, available presets: tech-brands, apple, cloud",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_front_matter() {
+        let yaml_str = indoc! {r#"
+        autocorrect:
+          rules:
+            fullwidth: off
+          spellcheck:
+            words:
+              - Foo
+        "#};
+
+        match parse_front_matter(yaml_str, Path::new(".")).unwrap() {
+            DocumentConfig::Config(config) => {
+                assert_eq!(Some(&SeverityMode::Off), config.rules.get("fullwidth"));
+                assert_eq!(vec!["Foo"], config.spellcheck.words);
+                assert_eq!(
+                    Some(&"Foo".to_owned()),
+                    config.spellcheck.word_map.get("foo")
+                );
+            }
+            config => panic!("unexpected {config:?}"),
+        }
+
+        assert!(matches!(
+            parse_front_matter("autocorrect: false", Path::new(".")).unwrap(),
+            DocumentConfig::Enable(false)
+        ));
+        assert!(parse_front_matter("autocorrect:\n  rules: foo", Path::new(".")).is_err());
+    }
+
     #[test]
     fn test_current_config_with_default_config_file() {
         let config = Config::current();
@@ -337,6 +676,7 @@ mod tests {
         for (k, v) in config.rules.clone() {
             match k.as_str() {
                 "spellcheck" => assert_eq!(SeverityMode::Warning, v),
+                "spellcheck-fuzzy" => assert_eq!(SeverityMode::Off, v),
                 "space-dash" => assert_eq!(SeverityMode::Error, v),
                 _ => assert_eq!(SeverityMode::Error, v),
             }
@@ -366,6 +706,19 @@ mod tests {
                 words: vec!["foo".to_string(), "bar".to_string(), "baz".to_string()],
                 ..Default::default()
             },
+            terms: vec![TermConfig {
+                pattern: "foo".to_owned(),
+                ..Default::default()
+            }],
+            scopes: map! {
+                "javascript".to_owned() => ScopeConfig::Kinds(vec![ScopeKind::Comment]),
+                "rust".to_owned() => ScopeConfig::Kinds(vec![ScopeKind::Comment])
+            },
+            width_maps: WidthMapsConfig {
+                halfwidth: map! { "～".to_owned() => "~".to_owned() },
+                ..Default::default()
+            },
+            space_policy: None,
         };
 
         let config1 = Config {
@@ -384,6 +737,22 @@ mod tests {
                 words: vec!["foo1".to_string(), "bar1".to_string()],
                 ..Default::default()
             },
+            terms: vec![TermConfig {
+                pattern: "bar".to_owned(),
+                ..Default::default()
+            }],
+            scopes: map! {
+                "rust".to_owned() => ScopeConfig::Kinds(vec![ScopeKind::String])
+            },
+            width_maps: WidthMapsConfig {
+                halfwidth: map! {
+                    "～".to_owned() => "".to_owned(),
+                    "＠".to_owned() => "@".to_owned()
+                },
+                fullwidth: map! { ";".to_owned() => "；".to_owned() },
+                ..Default::default()
+            },
+            space_policy: Some(SpacePolicyConfig::All(SpacePolicy::Remove)),
         };
 
         config.merge(&config1).unwrap();
@@ -420,6 +789,29 @@ mod tests {
                 "bar1".to_string()
             ]
         );
+
+        assert_eq!(
+            vec!["foo", "bar"],
+            config
+                .terms
+                .iter()
+                .map(|t| t.pattern.as_str())
+                .collect::<Vec<_>>()
+        );
+
+        let new_scopes = map! {
+            "javascript".to_owned() => ScopeConfig::Kinds(vec![ScopeKind::Comment]),
+            "rust".to_owned() => ScopeConfig::Kinds(vec![ScopeKind::String])
+        };
+        assert_eq!(new_scopes, config.scopes);
+
+        let new_halfwidth = map! {
+            "～".to_owned() => "".to_owned(),
+            "＠".to_owned() => "@".to_owned()
+        };
+        assert_eq!(new_halfwidth, config.width_maps.halfwidth);
+        assert_eq!(Some(&"；".to_owned()), config.width_maps.fullwidth.get(";"));
+        assert_eq!(SpacePolicy::Remove, config.space_policy("space-word"));
     }
 
     #[test]
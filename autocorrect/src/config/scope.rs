@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The kind of the node in a file to process.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ScopeKind {
+    /// The comments in code.
+    Comment,
+    /// The string literals in code, or the values in config files (JSON, YAML ...).
+    String,
+    /// The prose in document, e.g.: Markdown, HTML text.
+    Text,
+}
+
+impl ScopeKind {
+    /// Get the kind by the grammar rule name, e.g.: `string`, `text`, `comment`.
+    pub fn from_rule_name(rule_name: &str) -> Self {
+        match rule_name {
+            "comment" | "COMMENT" => ScopeKind::Comment,
+            "text" | "inner_text" => ScopeKind::Text,
+            _ => ScopeKind::String,
+        }
+    }
+}
+
+/// The node kinds to process for a file type, e.g.:
+///
+/// ```yml
+/// scopes:
+///   # All rules only for the comments in JavaScript.
+///   javascript: [comment]
+///   # Only spellcheck in comments, the other rules `*` in comments and strings.
+///   rust:
+///     "*": [comment, string]
+///     spellcheck: [comment]
+/// ```
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum ScopeConfig {
+    Kinds(Vec<ScopeKind>),
+    Rules(HashMap<String, Vec<ScopeKind>>),
+}
+
+impl ScopeConfig {
+    /// Return the rules out of scope for the node kind, `None` means all rules are out of scope.
+    pub fn disable_rules(&self, kind: ScopeKind) -> Option<Vec<String>> {
+        let rules = match self {
+            ScopeConfig::Kinds(kinds) => {
+                return if kinds.contains(&kind) {
+                    Some(vec![])
+                } else {
+                    None
+                };
+            }
+            ScopeConfig::Rules(rules) => rules,
+        };
+
+        let default_kinds = rules.get("*");
+        let rule_names = crate::rule::default_rule_names();
+        let disable_rules = rule_names
+            .iter()
+            .filter(|name| {
+                rules
+                    .get(name.as_str())
+                    .or(default_kinds)
+                    .is_some_and(|kinds| !kinds.contains(&kind))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if disable_rules.len() == rule_names.len() {
+            return None;
+        }
+
+        Some(disable_rules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use indoc::indoc;
+
+    #[test]
+    fn test_disable_rules() {
+        let config = Config::from_str(indoc! {r#"
+        scopes:
+          javascript: [comment]
+          rust:
+            "*": [comment, string]
+            spellcheck: [comment]
+          go:
+            spellcheck: [comment]
+        "#})
+        .unwrap();
+
+        let scope = config.scopes.get("javascript").unwrap();
+        assert_eq!(Some(vec![]), scope.disable_rules(ScopeKind::Comment));
+        assert_eq!(None, scope.disable_rules(ScopeKind::String));
+
+        let scope = config.scopes.get("rust").unwrap();
+        assert_eq!(Some(vec![]), scope.disable_rules(ScopeKind::Comment));
+        assert_eq!(
+            Some(vec!["spellcheck".to_string()]),
+            scope.disable_rules(ScopeKind::String)
+        );
+        assert_eq!(None, scope.disable_rules(ScopeKind::Text));
+
+        let scope = config.scopes.get("go").unwrap();
+        assert_eq!(
+            Some(vec!["spellcheck".to_string()]),
+            scope.disable_rules(ScopeKind::String)
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Add or remove the spaces between CJK and English,
+/// for `space-word`, `space-bracket`, `space-backticks` and `space-dash` rules.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SpacePolicy {
+    #[default]
+    Add,
+    Remove,
+}
+
+/// The space policy for all rules, or for each rule, e.g.:
+///
+/// ```yml
+/// spacePolicy: remove
+/// # Or for each rule, the others use `add`.
+/// spacePolicy:
+///   space-word: remove
+///   space-bracket: remove
+/// ```
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum SpacePolicyConfig {
+    All(SpacePolicy),
+    Rules(HashMap<String, SpacePolicy>),
+}
+
+impl SpacePolicyConfig {
+    pub fn get(&self, rule_name: &str) -> SpacePolicy {
+        match self {
+            SpacePolicyConfig::All(policy) => *policy,
+            SpacePolicyConfig::Rules(rules) => rules.get(rule_name).copied().unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn test_space_policy() {
+        let config = Config::from_str("spacePolicy: remove").unwrap();
+        assert_eq!(SpacePolicy::Remove, config.space_policy("space-word"));
+        assert_eq!(SpacePolicy::Remove, config.space_policy("space-dash"));
+
+        let config = Config::from_str("spacePolicy:\n  space-word: remove").unwrap();
+        assert_eq!(SpacePolicy::Remove, config.space_policy("space-word"));
+        assert_eq!(SpacePolicy::Add, config.space_policy("space-dash"));
+
+        let config = Config::from_str("rules:\n  space-word: 1").unwrap();
+        assert_eq!(SpacePolicy::Add, config.space_policy("space-word"));
+    }
+}
@@ -1,13 +1,44 @@
 use crate::keyword;
+use crate::rule::spellcheck::FuzzyIndex;
 
 use super::severity::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+    sync::{Arc, OnceLock},
+};
 
 lazy_static! {
     pub static ref PAIR_RE: regex::Regex = regex::Regex::new(r"\s*=\s*").unwrap();
 }
 
+/// Built-in word presets, with the same syntax as `dicts`.
+const PRESETS: &[(&str, &str)] = &[
+    (
+        "tech-brands",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/presets/spellcheck/tech-brands.txt"
+        )),
+    ),
+    (
+        "apple",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/presets/spellcheck/apple.txt"
+        )),
+    ),
+    (
+        "cloud",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/presets/spellcheck/cloud.txt"
+        )),
+    ),
+];
+
 #[derive(Deserialize, Serialize, Clone, Debug, Default)]
 pub struct SpellcheckConfig {
     #[serde(default)]
@@ -15,72 +46,192 @@ pub struct SpellcheckConfig {
     pub mode: Option<SeverityMode>,
     #[serde(default)]
     pub words: Vec<SpellcheckWord>,
+    /// The CJK terminology, with the same syntax as `words`, the CJK side of the word has no word boundary.
+    #[serde(default, rename = "cjkWords")]
+    pub cjk_words: Vec<SpellcheckWord>,
+    /// External dictionary files, with the same syntax as `words`, one word per line.
+    #[serde(default)]
+    pub dicts: Vec<String>,
+    /// Built-in word presets to use, e.g.: `tech-brands`, `apple`, `cloud`.
+    #[serde(default)]
+    pub presets: Vec<String>,
     /// key is always in lowercase
     /// value is the original word
     #[serde(skip)]
     pub word_map: HashMap<String, String>,
-    /// A tree to match words
+    /// The keys in `word_map` from `cjk_words`
+    #[serde(skip)]
+    pub(crate) cjk_keys: HashSet<String>,
+    /// The words of `dicts`, loaded by `load_dicts`, the key is the dict path.
+    #[serde(skip)]
+    pub(crate) dict_words: HashMap<String, Arc<Vec<SpellcheckWord>>>,
+    /// The excluded compound words, e.g.: `!登陆舰`
     #[serde(skip)]
-    pub matcher: keyword::Node,
+    pub(crate) excludes: Vec<String>,
+    /// A tree to match words, built on first use.
+    #[serde(skip)]
+    pub matcher: OnceLock<keyword::Node>,
+    /// A tree to match the excluded compound words, built on first use.
+    #[serde(skip)]
+    pub exclude_matcher: OnceLock<keyword::Node>,
+    /// The words indexed for `spellcheck-fuzzy`, built on first use.
+    #[serde(skip)]
+    pub(crate) fuzzy_index: OnceLock<FuzzyIndex>,
 }
 
 impl SpellcheckConfig {
     pub fn prepare(&mut self) {
-        self.matcher = keyword::Node::new(true);
-        self.matcher.add_keywords(
-            self.words
-                .iter()
-                .map(|w| {
-                    // get the = before, and trim
-                    // ios
-                    // wifi = Wi-Fi
-                    w.split('=').next().unwrap().trim()
-                })
-                .collect::<Vec<_>>(),
-        );
-        self.matcher.build();
-
-        if !self.words.is_empty() {
-            let mut lines = self.words.clone();
-
-            // Sort: Longger first, then alphabetically.
-            lines.sort_by(|a, b| {
-                let mut a = a.as_str();
-                let mut b = b.as_str();
-
-                let pair_a = PAIR_RE.split(a).collect::<Vec<_>>();
-                if pair_a.len() == 2 {
-                    a = pair_a[0];
-                }
-                let pair_b = PAIR_RE.split(b).collect::<Vec<_>>();
-                if pair_b.len() == 2 {
-                    b = pair_b[0];
-                }
-                a = a.trim();
-                b = b.trim();
+        // The later words have higher priority: presets < dicts < words < cjkWords
+        // The words start with `!` are the compound words to exclude, e.g.: `!登陆舰` for `登陆 = 登录`
+        let (excludes, words): (Vec<_>, Vec<_>) = self
+            .preset_words()
+            .into_iter()
+            .chain(
+                self.dicts
+                    .iter()
+                    .filter_map(|dict| self.dict_words.get(dict))
+                    .flat_map(|words| words.iter())
+                    .cloned(),
+            )
+            .chain(self.words.iter().cloned())
+            .chain(self.cjk_words.iter().cloned())
+            .partition(|w| w.trim_start().starts_with('!'));
 
-                b.len().cmp(&a.len()).then(a.cmp(b))
-            });
+        self.excludes = excludes
+            .iter()
+            .map(|w| w.trim_start().trim_start_matches('!').trim().to_string())
+            .collect();
 
-            for l in lines.iter().filter(|l| !l.trim().is_empty()) {
-                let mut left_str = l.as_str();
-                let mut right_str = l.as_str();
+        let cjk_words = self
+            .cjk_words
+            .iter()
+            .filter(|w| !w.trim_start().starts_with('!'))
+            .count();
+        let cjk_start = words.len() - cjk_words;
 
-                let pair = PAIR_RE.split(l).collect::<Vec<_>>();
-                if pair.len() == 2 {
-                    left_str = pair[0];
-                    right_str = pair[1];
+        self.word_map = HashMap::with_capacity(words.len());
+        self.cjk_keys = HashSet::with_capacity(cjk_words);
+        for (i, l) in words.iter().enumerate() {
+            if l.trim().is_empty() {
+                continue;
+            }
+
+            let mut left_str = l.as_str();
+            let mut right_str = l.as_str();
+
+            let pair = PAIR_RE.split(l).collect::<Vec<_>>();
+            if pair.len() == 2 {
+                left_str = pair[0];
+                right_str = pair[1];
+            }
+
+            left_str = left_str.trim();
+            right_str = right_str.trim();
+
+            let key = left_str.to_lowercase();
+
+            if i >= cjk_start {
+                self.cjk_keys.insert(key.clone());
+            } else {
+                self.cjk_keys.remove(&key);
+            }
+            self.word_map.insert(key, right_str.to_string());
+        }
+
+        // The words are changed, the matchers will be rebuilt on next use.
+        self.matcher = OnceLock::new();
+        self.exclude_matcher = OnceLock::new();
+        self.fuzzy_index = OnceLock::new();
+    }
+
+    /// The tree to match the words, it only builds once for a prepared config.
+    pub fn matcher(&self) -> &keyword::Node {
+        self.matcher.get_or_init(|| {
+            let mut matcher = keyword::Node::new(true);
+            matcher.add_keywords(self.word_map.keys());
+            matcher.build();
+            matcher
+        })
+    }
+
+    /// The tree to match the excluded compound words, it only builds once for a prepared config.
+    pub fn exclude_matcher(&self) -> &keyword::Node {
+        self.exclude_matcher.get_or_init(|| {
+            let mut matcher = keyword::Node::new(true);
+            matcher.add_keywords(self.excludes.iter());
+            matcher.build();
+            matcher
+        })
+    }
+
+    /// The words indexed for the fuzzy match, it only builds once for a prepared config.
+    pub(crate) fn fuzzy_index(&self) -> &FuzzyIndex {
+        self.fuzzy_index
+            .get_or_init(|| FuzzyIndex::new(&self.word_map))
+    }
+
+    /// Resolve the relative paths of `dicts` from the `base_dir`, and load the words in them.
+    ///
+    /// The words are loaded only once here, call `prepare` to use them.
+    pub fn load_dicts(&mut self, base_dir: &Path) -> Result<(), super::Error> {
+        for dict in self.dicts.iter_mut() {
+            let path = base_dir.join(&dict);
+            if !path.is_file() {
+                return Err(format!("Spellcheck dict file not found: {}", path.display()).into());
+            }
+
+            let dict_str = match fs::read_to_string(&path) {
+                Ok(dict_str) => dict_str,
+                Err(err) => {
+                    return Err(format!(
+                        "Spellcheck dict file read error: {}, {err}",
+                        path.display()
+                    )
+                    .into())
                 }
+            };
 
-                left_str = left_str.trim();
-                right_str = right_str.trim();
+            *dict = path.to_string_lossy().to_string();
+            self.dict_words
+                .insert(dict.clone(), Arc::new(parse_words(&dict_str)));
+        }
 
-                let key = left_str.to_lowercase();
+        Ok(())
+    }
 
-                self.word_map.insert(key.clone(), right_str.to_string());
+    /// Make sure the `presets` are all built-in.
+    pub fn check_presets(&self) -> Result<(), super::Error> {
+        for preset in self.presets.iter() {
+            if !PRESETS.iter().any(|(name, _)| name == preset) {
+                let names = PRESETS.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+                return Err(format!(
+                    "Unknown spellcheck preset: {preset}, available presets: {}",
+                    names.join(", ")
+                )
+                .into());
             }
         }
+
+        Ok(())
     }
+
+    fn preset_words(&self) -> Vec<SpellcheckWord> {
+        PRESETS
+            .iter()
+            .filter(|(name, _)| self.presets.iter().any(|p| p == name))
+            .flat_map(|(_, preset_str)| parse_words(preset_str))
+            .collect()
+    }
+}
+
+/// Parse the words in dict file, skip the blank lines and `#` comments.
+fn parse_words(dict_str: &str) -> Vec<SpellcheckWord> {
+    dict_str
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(String::from)
+        .collect()
 }
 
 type SpellcheckWord = String;
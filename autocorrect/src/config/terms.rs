@@ -0,0 +1,91 @@
+use super::severity::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// A prohibited or discouraged term, e.g.:
+///
+/// ```yml
+/// terms:
+///   - pattern: master/slave
+///     replacement: primary/replica
+///     message: Use inclusive language.
+///     severity: warning
+/// ```
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+pub struct TermConfig {
+    /// The text to match (case insensitive), or a regexp in `/.../`, e.g.: `/点击(这里|此处)/`
+    pub pattern: String,
+    /// The suggested replacement, `$1` is allowed for regexp pattern.
+    #[serde(default)]
+    pub replacement: Option<String>,
+    /// The message to describe the problem.
+    #[serde(default)]
+    pub message: String,
+    /// Use `rules.terms` severity if not set.
+    #[serde(default)]
+    pub severity: Option<SeverityMode>,
+    #[serde(skip)]
+    pub regex: Option<Regex>,
+}
+
+impl TermConfig {
+    /// Compile the pattern into regexp.
+    pub fn prepare(&mut self) -> Result<(), super::Error> {
+        let is_regexp = self.is_regexp();
+        let pattern = if is_regexp {
+            self.pattern[1..self.pattern.len() - 1].to_string()
+        } else {
+            // Only match the whole English word, e.g.: `master` should not match `mastered`
+            let mut pattern = regex::escape(&self.pattern);
+            if self
+                .pattern
+                .starts_with(|c: char| c.is_ascii_alphanumeric())
+            {
+                pattern = format!(r"(?-u:\b){pattern}");
+            }
+            if self.pattern.ends_with(|c: char| c.is_ascii_alphanumeric()) {
+                pattern.push_str(r"(?-u:\b)");
+            }
+            format!("(?i){pattern}")
+        };
+
+        match Regex::new(&pattern) {
+            Ok(regex) => self.regex = Some(regex),
+            Err(err) => {
+                return Err(format!("Invalid term pattern: {}, {err}", self.pattern).into());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_regexp(&self) -> bool {
+        self.pattern.len() > 2 && self.pattern.starts_with('/') && self.pattern.ends_with('/')
+    }
+
+    /// Replace the matched text with the replacement, return None if no replacement.
+    pub fn replace(&self, text: &str) -> Option<String> {
+        let regex = self.regex.as_ref()?;
+        let replacement = self.replacement.as_ref()?;
+
+        let out = if self.is_regexp() {
+            regex.replace_all(text, replacement.as_str())
+        } else {
+            regex.replace_all(text, regex::NoExpand(replacement))
+        };
+
+        Some(out.to_string())
+    }
+
+    /// The message for lint, use the default message if not set.
+    pub fn message(&self) -> String {
+        if !self.message.is_empty() {
+            return self.message.clone();
+        }
+
+        match &self.replacement {
+            Some(replacement) => format!("Use `{}` instead of `{}`.", replacement, self.pattern),
+            None => format!("Avoid using `{}`.", self.pattern),
+        }
+    }
+}
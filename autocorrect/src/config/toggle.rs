@@ -1,3 +1,30 @@
+//! AutoCorrect toggle directives, e.g.: `// autocorrect-disable space-word`
+//!
+//! The toggle state is a stack of frames, each frame disables or enables some rules (empty for all rules).
+//! A rule is decided by the innermost frame that mentions it, and it is enabled if no frame mentions it.
+//!
+//! - `autocorrect-disable [rules]` (or `autocorrect: false`) opens a disable frame.
+//! - `autocorrect-enable rules` closes the innermost frame, if it is a disable frame of the same rules,
+//!   otherwise it opens an enable frame to re-enable the rules inside a disabled region.
+//! - `autocorrect-enable` (or `autocorrect: true`) closes the innermost disable frame,
+//!   and the enable frames opened inside it.
+//!
+//! For example:
+//!
+//! ```ignore
+//! // autocorrect-disable space-word
+//! // autocorrect-disable
+//! //   All rules are disabled.
+//! //   autocorrect-enable fullwidth
+//! //   Only fullwidth is enabled.
+//! // autocorrect-enable
+//! // Only space-word is disabled.
+//! // autocorrect-enable
+//! // All rules are enabled.
+//! ```
+//!
+//! The embedded code (e.g.: Codeblock in Markdown, script in HTML) inherits the toggle state of the parent,
+//! and the directives inside it only take effect in the embedded code.
 use std::collections::HashMap;
 
 use pest::Parser;
@@ -23,69 +50,265 @@ impl Default for Toggle {
 }
 
 impl Toggle {
+    /// Return Some(enable) if this toggle mentions the rule, otherwise None.
     pub fn match_rule(&self, rule_name: &str) -> Option<bool> {
         match self {
             Toggle::None => None,
             Toggle::Disable(rules) => {
-                if rules.is_empty() {
+                if rules.is_empty() || rules.iter().any(|r| r == rule_name) {
                     Some(false)
                 } else {
-                    Some(!rules.contains(&rule_name.to_string()))
+                    None
                 }
             }
             Toggle::Enable(rules) => {
-                if rules.is_empty() {
+                if rules.is_empty() || rules.iter().any(|r| r == rule_name) {
                     Some(true)
                 } else {
-                    Some(rules.contains(&rule_name.to_string()))
+                    None
                 }
             }
         }
     }
 
-    pub fn disable_rules(&self) -> HashMap<String, bool> {
+    /// Return the rule names of this toggle, empty for all rules.
+    pub fn rule_names(&self) -> &[String] {
         match self {
-            Toggle::Disable(rules) => {
-                let mut map = HashMap::new();
-                for rule in rules {
-                    map.insert(rule.to_string(), true);
+            Toggle::Disable(rules) | Toggle::Enable(rules) => rules,
+            Toggle::None => &[],
+        }
+    }
+
+    fn is_same_rules(&self, rules: &[String]) -> bool {
+        let mut a = self.rule_names().to_vec();
+        let mut b = rules.to_vec();
+        a.sort();
+        b.sort();
+        a == b
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+struct Frame {
+    toggle: Toggle,
+    // Index of the directive that opened this frame
+    directive: Option<usize>,
+}
+
+/// The toggle state, a stack of disable / enable frames.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct ToggleStack {
+    frames: Vec<Frame>,
+    // Rules disabled temporary by the parser, not affected by the directives.
+    overrides: Vec<String>,
+}
+
+impl ToggleStack {
+    /// Apply a toggle directive, return the directive indexes of the closed frames.
+    pub fn apply(&mut self, toggle: Toggle, directive: Option<usize>) -> Vec<usize> {
+        let mut closed = vec![];
+
+        match toggle {
+            Toggle::None => {}
+            Toggle::Disable(_) => self.frames.push(Frame { toggle, directive }),
+            Toggle::Enable(rules) if rules.is_empty() => {
+                while let Some(frame) = self.frames.pop() {
+                    closed.extend(frame.directive);
+                    if matches!(frame.toggle, Toggle::Disable(_)) {
+                        break;
+                    }
                 }
-                map
             }
-            _ => HashMap::new(),
+            Toggle::Enable(rules) => match self.frames.last() {
+                Some(frame)
+                    if matches!(frame.toggle, Toggle::Disable(_))
+                        && frame.toggle.is_same_rules(&rules) =>
+                {
+                    closed.extend(self.frames.pop().and_then(|f| f.directive));
+                }
+                _ => self.frames.push(Frame {
+                    toggle: Toggle::Enable(rules),
+                    directive,
+                }),
+            },
         }
+
+        closed
     }
 
-    // Merge two toggle if it posible, otherwise override
-    pub fn merge(&mut self, new_toggle: Self) {
-        match new_toggle {
-            Toggle::Disable(rules) => {
-                if let Toggle::Disable(old_rules) = self {
-                    if !old_rules.is_empty() {
-                        old_rules.extend(rules.clone());
-                    }
+    /// Disable a rule temporary, it will not be closed by the directives,
+    /// the caller should call `pop_override` after use.
+    pub fn push_override(&mut self, rule_name: &str) {
+        self.overrides.push(rule_name.to_owned());
+    }
 
-                    if rules.is_empty() {
-                        old_rules.clear();
-                    }
-                } else {
-                    *self = Toggle::Disable(rules);
+    pub fn pop_override(&mut self) {
+        self.overrides.pop();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty() && self.overrides.is_empty()
+    }
+
+    /// Return true if the rule is enabled.
+    pub fn match_rule(&self, rule_name: &str) -> bool {
+        if self.overrides.iter().any(|r| r == rule_name) {
+            return false;
+        }
+
+        self.decide_frame(rule_name)
+            .map(|idx| self.frames[idx].toggle.match_rule(rule_name) == Some(true))
+            .unwrap_or(true)
+    }
+
+    /// Return true if there is any rule enabled.
+    pub fn is_enabled(&self) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+
+        crate::rule::default_rule_names()
+            .iter()
+            .any(|name| self.match_rule(name))
+    }
+
+    /// Return the disabled rules, for `format_or_lint_with_disable_rules`.
+    pub fn disable_rules(&self) -> HashMap<String, bool> {
+        let mut map = HashMap::new();
+        if self.is_empty() {
+            return map;
+        }
+
+        for name in self.rule_names() {
+            if !self.match_rule(&name) {
+                map.insert(name, true);
+            }
+        }
+        map
+    }
+
+    /// The built-in rule names, and the other rule names mentioned by the directives,
+    /// e.g.: `autocorrect-disable foo` keeps `foo` for the third-party rules.
+    fn rule_names(&self) -> Vec<String> {
+        let mut names = crate::rule::default_rule_names();
+        for frame in self.frames.iter() {
+            for name in frame.toggle.rule_names() {
+                if !names.contains(name) {
+                    names.push(name.clone());
                 }
             }
-            Toggle::Enable(rules) => {
-                if let Toggle::Enable(old_rules) = self {
-                    if !old_rules.is_empty() {
-                        old_rules.extend(rules.clone());
-                    }
+        }
+        names
+    }
 
-                    if rules.is_empty() {
-                        old_rules.clear();
-                    }
-                } else {
-                    *self = Toggle::Enable(rules);
+    /// Return the disable directives in effect, with the rules they disabled.
+    pub fn disable_directives(&self) -> Vec<(usize, HashMap<String, bool>)> {
+        let mut items: Vec<(usize, HashMap<String, bool>)> = vec![];
+        if self.frames.is_empty() {
+            return items;
+        }
+
+        for name in crate::rule::default_rule_names() {
+            let Some(idx) = self.decide_frame(&name) else {
+                continue;
+            };
+            let frame = &self.frames[idx];
+            let Some(directive) = frame.directive else {
+                continue;
+            };
+            if frame.toggle.match_rule(&name) != Some(false) {
+                continue;
+            }
+
+            match items.iter_mut().find(|(i, _)| *i == directive) {
+                Some((_, rules)) => {
+                    rules.insert(name, true);
                 }
+                None => items.push((directive, map! { name => true })),
+            }
+        }
+
+        items
+    }
+
+    /// Index of the innermost frame that mentions the rule.
+    fn decide_frame(&self, rule_name: &str) -> Option<usize> {
+        self.frames
+            .iter()
+            .rposition(|frame| frame.toggle.match_rule(rule_name).is_some())
+    }
+}
+
+/// A toggle directive comment in the source, e.g.: `// autocorrect-disable space-word`
+#[derive(Debug, Clone)]
+pub struct Directive {
+    pub line: usize,
+    pub col: usize,
+    pub raw: String,
+    pub toggle: Toggle,
+    /// Whether this directive has suppressed any change
+    pub used: bool,
+    /// Whether this directive has been closed by a following directive
+    pub closed: bool,
+    /// Whether this directive appears before any text, e.g.: `// autocorrect: false` in the first line
+    pub leading: bool,
+}
+
+/// Collect the toggle directives for report unused, unknown or unclosed directives.
+#[derive(Debug, Clone, Default)]
+pub struct Directives {
+    pub items: Vec<Directive>,
+    has_text: bool,
+}
+
+impl Directives {
+    /// Add a directive, return the index of it.
+    pub fn push(&mut self, line: usize, col: usize, raw: &str, toggle: Toggle) -> usize {
+        self.items.push(Directive {
+            line,
+            col,
+            raw: raw.trim().to_string(),
+            toggle,
+            used: false,
+            closed: false,
+            leading: !self.has_text,
+        });
+
+        self.items.len() - 1
+    }
+
+    /// Mark there has text before next directive
+    pub fn mark_text(&mut self) {
+        self.has_text = true;
+    }
+
+    /// Return a copy for the embedded code, the directives inside it only take effect in the embedded code.
+    pub fn inherit(&self) -> Self {
+        Directives {
+            items: self.items.clone(),
+            has_text: false,
+        }
+    }
+
+    /// Mark the directives as closed
+    pub fn close(&mut self, indexes: &[usize]) {
+        for idx in indexes {
+            self.items[*idx].closed = true;
+        }
+    }
+
+    /// Merge back the directives from a sub result (e.g.: Codeblock in Markdown),
+    /// the sub result was started with a clone of this.
+    pub fn merge(&mut self, other: Directives, line_offset: usize) {
+        let base_len = self.items.len();
+
+        for (idx, mut directive) in other.items.into_iter().enumerate() {
+            if idx < base_len {
+                self.items[idx].used |= directive.used;
+            } else {
+                directive.line += line_offset;
+                self.items.push(directive);
             }
-            _ => *self = new_toggle,
         }
     }
 }
@@ -126,11 +349,20 @@ mod tests {
 
     use super::*;
 
+    fn stack(toggles: &[&str]) -> ToggleStack {
+        let mut stack = ToggleStack::default();
+        for t in toggles {
+            stack.apply(parse(t), None);
+        }
+        stack
+    }
+
     #[test]
     fn it_match_rule() {
         assert_eq!(Toggle::Enable(vec![]).match_rule("rule"), Some(true));
         assert_eq!(Toggle::Enable(vec![]).match_rule("foo"), Some(true));
         assert_eq!(Toggle::Enable(vec![]).match_rule(""), Some(true));
+        assert_eq!(Toggle::Disable(vec![]).match_rule("foo"), Some(false));
 
         assert_eq!(
             Toggle::Enable(vec!["foo".to_owned()]).match_rule("foo"),
@@ -138,11 +370,7 @@ mod tests {
         );
         assert_eq!(
             Toggle::Enable(vec!["bar".to_owned()]).match_rule("foo"),
-            Some(false)
-        );
-        assert_eq!(
-            Toggle::Enable(vec!["foo".to_owned(), "bar".to_owned()]).match_rule("foo"),
-            Some(true)
+            None
         );
         assert_eq!(
             Toggle::Enable(vec!["foo".to_owned(), "bar".to_owned()]).match_rule("bar"),
@@ -150,12 +378,17 @@ mod tests {
         );
         assert_eq!(
             Toggle::Enable(vec!["foo".to_owned(), "bar".to_owned()]).match_rule("dar"),
-            Some(false)
+            None
         );
         assert_eq!(
-            Toggle::Enable(vec!["foo".to_owned(), "bar".to_owned()]).match_rule(""),
+            Toggle::Disable(vec!["foo".to_owned(), "bar".to_owned()]).match_rule("foo"),
             Some(false)
         );
+        assert_eq!(
+            Toggle::Disable(vec!["foo".to_owned(), "bar".to_owned()]).match_rule("dar"),
+            None
+        );
+        assert_eq!(Toggle::None.match_rule("foo"), None);
     }
 
     #[test]
@@ -208,68 +441,142 @@ mod tests {
 
     #[test]
     fn test_disable_rules() {
-        // disable_rules
-        assert_eq!(
-            Some(&true),
-            parse("// autocorrect-disable foo,Bar, Foo-bAr_dar")
-                .disable_rules()
-                .get("foo")
-        );
+        let disable_rules =
+            stack(&["// autocorrect-disable space-word,Fullwidth, Foo-bAr_dar"]).disable_rules();
+        assert_eq!(Some(&true), disable_rules.get("space-word"));
+        assert_eq!(Some(&true), disable_rules.get("fullwidth"));
+        // Unknown rules are kept for the third-party rules
+        assert_eq!(Some(&true), disable_rules.get("foo-bar_dar"));
+        assert_eq!(None, disable_rules.get("space-dash"));
+
+        let disable_rules = stack(&[
+            "// autocorrect-disable",
+            "// autocorrect-enable foo,space-word",
+        ])
+        .disable_rules();
+        assert_eq!(None, disable_rules.get("foo"));
+        assert_eq!(None, disable_rules.get("space-word"));
+        assert_eq!(Some(&true), disable_rules.get("fullwidth"));
+
+        assert!(stack(&[]).disable_rules().is_empty());
         assert_eq!(
-            Some(&true),
-            parse("// autocorrect-disable foo,Bar, Foo-bAr_dar")
-                .disable_rules()
-                .get("bar")
+            crate::rule::default_rule_names().len(),
+            stack(&["autocorrect: false"]).disable_rules().len()
         );
+    }
+
+    #[test]
+    fn test_toggle_stack() {
+        // Nested disable
+        let mut toggle = stack(&["autocorrect-disable space-word", "autocorrect-disable"]);
+        assert!(!toggle.is_enabled());
+        toggle.apply(parse("autocorrect-enable"), None);
+        assert!(toggle.is_enabled());
+        assert!(!toggle.match_rule("space-word"));
+        assert!(toggle.match_rule("fullwidth"));
+        toggle.apply(parse("autocorrect-enable"), None);
+        assert!(toggle.is_empty());
+
+        // Enable one rule while others stay disabled
+        let mut toggle = stack(&["autocorrect: false", "autocorrect-enable fullwidth"]);
+        assert!(toggle.is_enabled());
+        assert!(toggle.match_rule("fullwidth"));
+        assert!(!toggle.match_rule("space-word"));
         assert_eq!(
-            Some(&true),
-            parse("// autocorrect-disable foo,Bar, Foo-bAr_dar")
-                .disable_rules()
-                .get("foo-bar_dar")
+            crate::rule::default_rule_names().len() - 1,
+            toggle.disable_rules().len()
         );
+        // Close the disable frame, and the enable frame inside it
+        toggle.apply(parse("autocorrect: true"), None);
+        assert!(toggle.is_empty());
+
+        // Enable the same rules to close the disable frame
+        let mut toggle = stack(&["autocorrect-disable space-word, fullwidth"]);
+        toggle.apply(parse("autocorrect-enable fullwidth,space-word"), None);
+        assert!(toggle.is_empty());
+
+        // Enable part of rules
+        let mut toggle = stack(&["autocorrect-disable space-word, fullwidth"]);
+        toggle.apply(parse("autocorrect-enable fullwidth"), None);
+        assert!(!toggle.match_rule("space-word"));
+        assert!(toggle.match_rule("fullwidth"));
+
+        // Enable without any frame
+        let mut toggle = stack(&["autocorrect-enable"]);
+        assert!(toggle.is_empty());
+        toggle.apply(parse("autocorrect-enable space-word"), None);
+        assert!(toggle.match_rule("space-word"));
+        assert!(toggle.is_enabled());
+    }
+
+    #[test]
+    fn test_toggle_stack_closed_directives() {
+        let mut toggle = ToggleStack::default();
+        assert!(toggle
+            .apply(parse("autocorrect-disable space-word"), Some(0))
+            .is_empty());
+        assert!(toggle
+            .apply(parse("autocorrect-disable"), Some(1))
+            .is_empty());
+        assert!(toggle
+            .apply(parse("autocorrect-enable fullwidth"), Some(2))
+            .is_empty());
+
+        let directives = toggle.disable_directives();
+        assert_eq!(1, directives.len());
+        assert_eq!(1, directives[0].0);
+        assert_eq!(None, directives[0].1.get("fullwidth"));
+        assert_eq!(Some(&true), directives[0].1.get("space-word"));
+
+        assert_eq!(vec![2, 1], toggle.apply(parse("autocorrect-enable"), None));
+        let directives = toggle.disable_directives();
         assert_eq!(
-            None,
-            parse("// autocorrect-disable foo,Bar, Foo-bAr_dar")
-                .disable_rules()
-                .get("foo-bar")
+            vec![(0, map! { "space-word".to_owned() => true })],
+            directives
         );
+        assert_eq!(vec![0], toggle.apply(parse("autocorrect-enable"), None));
     }
 
     #[test]
-    fn test_merge() {
-        let mut toggle = Toggle::Enable(vec!["foo".to_owned()]);
-        toggle.merge(Toggle::Enable(vec!["bar".to_owned()]));
+    fn test_directives() {
+        let mut directives = Directives::default();
         assert_eq!(
-            Toggle::Enable(vec!["foo".to_owned(), "bar".to_owned()]),
-            toggle
+            0,
+            directives.push(
+                1,
+                1,
+                "// autocorrect: false",
+                parse("// autocorrect: false")
+            )
         );
-        toggle.merge(Toggle::Enable(vec![]));
-        assert_eq!(Toggle::Enable(vec![]), toggle);
-        toggle.merge(Toggle::Enable(vec!["foo".to_owned()]));
-        assert_eq!(Toggle::Enable(vec![]), toggle);
-
-        let mut toggle = Toggle::Disable(vec!["foo".to_owned(), "bar".to_owned()]);
-        toggle.merge(Toggle::Disable(vec!["dar".to_owned()]));
+        directives.mark_text();
         assert_eq!(
-            Toggle::Disable(vec!["foo".to_owned(), "bar".to_owned(), "dar".to_owned()]),
-            toggle
+            1,
+            directives.push(
+                4,
+                1,
+                " // autocorrect-disable foo ",
+                parse("// autocorrect-disable foo")
+            )
         );
-        toggle.merge(Toggle::Disable(vec![]));
-        assert_eq!(Toggle::Disable(vec![]), toggle);
-        toggle.merge(Toggle::Disable(vec!["foo".to_owned()]));
-        assert_eq!(Toggle::Disable(vec![]), toggle);
-
-        // Merge with disable enum value, override
-        let mut toggle = Toggle::Enable(vec!["foo".to_owned(), "bar".to_owned()]);
-        toggle.merge(Toggle::Disable(vec!["dar".to_owned()]));
-        assert_eq!(Toggle::Disable(vec!["dar".to_owned()]), toggle);
-        toggle.merge(Toggle::None);
-        assert_eq!(Toggle::None, toggle);
-
-        let mut toggle = Toggle::Disable(vec!["foo".to_owned(), "bar".to_owned()]);
-        toggle.merge(Toggle::Enable(vec!["dar".to_owned()]));
-        assert_eq!(Toggle::Enable(vec!["dar".to_owned()]), toggle);
-        toggle.merge(Toggle::None);
-        assert_eq!(Toggle::None, toggle);
+        directives.close(&[0]);
+
+        let mut sub = directives.clone();
+        sub.items[1].used = true;
+        sub.push(
+            2,
+            1,
+            "// autocorrect-enable",
+            parse("// autocorrect-enable"),
+        );
+        directives.merge(sub, 10);
+
+        let items = &directives.items;
+        assert_eq!(3, items.len());
+        assert!(items[0].leading && items[0].closed && !items[0].used);
+        assert!(!items[1].leading && !items[1].closed && items[1].used);
+        assert_eq!("// autocorrect-disable foo", items[1].raw);
+        assert_eq!(vec!["foo".to_owned()], items[1].toggle.rule_names());
+        assert_eq!(12, items[2].line);
     }
 }
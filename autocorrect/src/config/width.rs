@@ -0,0 +1,141 @@
+use crate::rule::fullwidth::Punctuations;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::OnceLock};
+
+/// Extend or override the width conversion tables, e.g.:
+///
+/// ```yml
+/// widthMaps:
+///   halfwidth:
+///     "～": "~"
+///     "＠": "@"
+///   fullwidth:
+///     ";": "；"
+///     # Empty to disable the conversion of the char
+///     ":": ""
+///   # Override the maps above for the file types, e.g.: keep `％` in Markdown
+///   fileTypes:
+///     markdown:
+///       halfwidth:
+///         "％": ""
+/// ```
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+pub struct WidthMapsConfig {
+    /// The fullwidth chars to halfwidth for `halfwidth-word` rule.
+    #[serde(default)]
+    pub halfwidth: HashMap<String, String>,
+    /// The halfwidth punctuations to fullwidth near the CJK for `fullwidth` rule.
+    #[serde(default)]
+    pub fullwidth: HashMap<String, String>,
+    /// The maps for the file types, they override the maps above in these files.
+    #[serde(default, rename = "fileTypes")]
+    pub file_types: HashMap<String, WidthMapsConfig>,
+    /// The fullwidth punctuations with the config applied, built on first use.
+    #[serde(skip)]
+    pub(crate) fullwidth_punctuations: OnceLock<Punctuations>,
+}
+
+impl WidthMapsConfig {
+    pub fn prepare(&mut self) {
+        // The maps are changed, the punctuations will be rebuilt on next use.
+        self.fullwidth_punctuations = OnceLock::new();
+    }
+
+    /// Make sure the keys of the maps are all single char.
+    pub fn check(&self) -> Result<(), super::Error> {
+        for key in self.halfwidth.keys().chain(self.fullwidth.keys()) {
+            if key.chars().count() != 1 {
+                return Err(
+                    format!("Invalid width map key: {key:?}, must be a single char").into(),
+                );
+            }
+        }
+
+        for maps in self.file_types.values() {
+            maps.check()?;
+        }
+
+        Ok(())
+    }
+
+    /// Merge the maps of other config, the maps of the same file type are merged too.
+    pub fn merge(&mut self, other: &WidthMapsConfig) {
+        other.halfwidth.iter().for_each(|(k, v)| {
+            self.halfwidth.insert(k.to_owned(), v.to_owned());
+        });
+        other.fullwidth.iter().for_each(|(k, v)| {
+            self.fullwidth.insert(k.to_owned(), v.to_owned());
+        });
+        other.file_types.iter().for_each(|(file_type, maps)| {
+            self.file_types
+                .entry(file_type.to_owned())
+                .or_default()
+                .merge(maps);
+        });
+    }
+
+    /// Get the halfwidth char of a fullwidth char by the config,
+    /// `Some("")` means the conversion is disabled.
+    pub fn get_halfwidth(&self, part: &str) -> Option<&str> {
+        self.halfwidth.get(part).map(|s| s.as_str())
+    }
+
+    /// The fullwidth punctuations with the config applied, it only builds once for a prepared config.
+    pub(crate) fn fullwidth_punctuations(&self) -> &Punctuations {
+        if self.fullwidth.is_empty() {
+            return Punctuations::default_ref();
+        }
+
+        self.fullwidth_punctuations
+            .get_or_init(|| Punctuations::new(&self.fullwidth))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+    use indoc::indoc;
+
+    #[test]
+    fn test_check() {
+        let config = Config::from_str("widthMaps:\n  halfwidth:\n    \"～\": \"~\"").unwrap();
+        assert_eq!(Some("~"), config.width_maps.get_halfwidth("～"));
+        assert_eq!(None, config.width_maps.get_halfwidth("＠"));
+
+        let err = Config::from_str("widthMaps:\n  fullwidth:\n    \";;\": \"；\"").unwrap_err();
+        assert_eq!(
+            "Invalid width map key: \";;\", must be a single char",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_file_types() {
+        let config = Config::from_str(indoc! {r#"
+        widthMaps:
+          halfwidth:
+            "％": "%"
+          fileTypes:
+            markdown:
+              halfwidth:
+                "％": ""
+        "#})
+        .unwrap();
+        let last_config = crate::config::set_document_config(Some(&config)).unwrap();
+
+        assert_eq!("占比 50％", crate::format_for("占比 50％", "md").out);
+        assert_eq!("// 占比 50%", crate::format_for("// 占比 50％", "js").out);
+        assert_eq!("占比 50%", crate::format("占比 50％"));
+
+        crate::config::restore_document_config(last_config);
+
+        let err = Config::from_str(
+            "widthMaps:\n  fileTypes:\n    md:\n      halfwidth:\n        \"％％\": \"\"",
+        )
+        .unwrap_err();
+        assert_eq!(
+            "Invalid width map key: \"％％\", must be a single char",
+            err.to_string()
+        );
+    }
+}
@@ -1,18 +1,51 @@
 //! Aho-Corasick algorithm for finding multiple keywords in a text.
 //!
 //! https://en.wikipedia.org/wiki/Aho%E2%80%93Corasick_algorithm
+//!
+//! The automaton is stored as a flat list of states, the transitions of each state are sorted
+//! by char for binary search, and the fail links and output links are the state indexes,
+//! so a large dictionary can be built without cloning any sub-tree.
 use std::collections::{HashMap, VecDeque};
 use std::fmt;
 
+const ROOT: usize = 0;
+
 #[derive(Clone, Eq, PartialEq, Debug, Default)]
+struct State {
+    /// The next states, sorted by char.
+    transitions: Vec<(char, u32)>,
+    /// The state of the longest proper suffix in the tree.
+    fail: u32,
+    /// The index of the keyword that ends at this state.
+    keyword: Option<u32>,
+    /// The nearest state in the fail links that has a keyword.
+    output: Option<u32>,
+}
+
+impl State {
+    fn next(&self, c: char) -> Option<usize> {
+        self.transitions
+            .binary_search_by_key(&c, |(k, _)| *k)
+            .ok()
+            .map(|i| self.transitions[i].1 as usize)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
 pub struct Node {
-    children: HashMap<char, Node>,
-    fail: Option<Box<Node>>,
-    keywords: Vec<String>,
+    states: Vec<State>,
+    /// The keywords with the chars count.
+    keywords: Vec<(String, usize)>,
     /// Whether the tree is case insensitive.
     case_insensitive: bool,
 }
 
+impl Default for Node {
+    fn default() -> Self {
+        Node::new(false)
+    }
+}
+
 impl fmt::Display for Node {
     /// Return a nested tree structure.
     ///
@@ -30,19 +63,7 @@ impl fmt::Display for Node {
     /// |-|-d
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut items = self.children.iter().collect::<Vec<_>>();
-        items.sort_by(|a, b| a.0.cmp(b.0));
-        for (c, child) in items {
-            let prefix = String::from("|-");
-
-            writeln!(f, "{}{}", prefix, c)?;
-            if !child.children.is_empty() && !prefix.is_empty() {
-                let child_text = format!("{}", child);
-                writeln!(f, "{}{}", prefix, child_text.trim())?;
-            }
-        }
-
-        Ok(())
+        write!(f, "{}", self.tree_text(ROOT))
     }
 }
 
@@ -73,8 +94,7 @@ pub type MatchedResult = HashMap<String, Vec<Span>>;
 impl Node {
     pub fn new(case_insensitive: bool) -> Self {
         Node {
-            children: HashMap::new(),
-            fail: None,
+            states: vec![State::default()],
             keywords: Vec::new(),
             case_insensitive,
         }
@@ -87,48 +107,75 @@ impl Node {
         }
     }
 
-    /// Add a single keyword to the tree.
+    /// Add a single keyword to the tree, the duplicate keyword will be ignored.
     fn add_keyword(&mut self, keyword: impl AsRef<str>) {
-        let case_insensitive = self.case_insensitive;
-        let mut node = self;
         let mut keyword = String::from(keyword.as_ref());
-        if case_insensitive {
+        if keyword.is_empty() {
+            return;
+        }
+        if self.case_insensitive {
             keyword = keyword.to_lowercase();
         }
 
-        for char in keyword.chars() {
-            node = node
-                .children
-                .entry(char)
-                .or_insert(Node::new(case_insensitive));
+        let mut state = ROOT;
+        for c in keyword.chars() {
+            state = match self.states[state].next(c) {
+                Some(next) => next,
+                None => {
+                    let next = self.states.len();
+                    self.states.push(State::default());
+
+                    let transitions = &mut self.states[state].transitions;
+                    let pos = transitions.partition_point(|(k, _)| *k < c);
+                    transitions.insert(pos, (c, next as u32));
+                    next
+                }
+            };
+        }
+
+        if self.states[state].keyword.is_none() {
+            self.states[state].keyword = Some(self.keywords.len() as u32);
+            let len = keyword.chars().count();
+            self.keywords.push((keyword, len));
         }
-        node.keywords.push(keyword);
     }
 
-    /// Build fail pointers for the tree.
+    /// Build fail links and output links for the tree.
     pub fn build(&mut self) {
         let mut queue = VecDeque::new();
-        let node = self.clone();
 
-        for child in self.children.values_mut() {
-            child.fail = Some(Box::new(node.clone()));
+        for i in 0..self.states[ROOT].transitions.len() {
+            let child = self.states[ROOT].transitions[i].1 as usize;
+            self.states[child].fail = ROOT as u32;
+            self.states[child].output = None;
             queue.push_back(child);
         }
 
         while let Some(current) = queue.pop_front() {
-            for (char, child) in current.children.iter_mut() {
-                let mut fail_node = current.fail.clone().unwrap();
-                while !fail_node.children.contains_key(char) && fail_node.fail.is_some() {
-                    fail_node = fail_node.fail.clone().unwrap();
-                }
+            for i in 0..self.states[current].transitions.len() {
+                let (c, child) = self.states[current].transitions[i];
+                let child = child as usize;
+
+                let mut fail = self.states[current].fail as usize;
+                let fail = loop {
+                    if let Some(next) = self.states[fail].next(c) {
+                        break next;
+                    }
+                    if fail == ROOT {
+                        break ROOT;
+                    }
+                    fail = self.states[fail].fail as usize;
+                };
 
-                if let Some(fail_child) = fail_node.children.get(char) {
-                    child.fail = Some(Box::new(fail_child.clone()));
-                    child.keywords.extend(fail_child.keywords.iter().cloned());
+                let fail_state = &self.states[fail];
+                let output = if fail_state.keyword.is_some() {
+                    Some(fail as u32)
                 } else {
-                    child.fail = Some(fail_node);
-                }
+                    fail_state.output
+                };
 
+                self.states[child].fail = fail as u32;
+                self.states[child].output = output;
                 queue.push_back(child);
             }
         }
@@ -146,8 +193,11 @@ impl Node {
     /// ```
     pub fn match_keywords(&self, text: &str) -> MatchedResult {
         let mut result = MatchedResult::new();
-        let mut node = self;
+        if self.keywords.is_empty() {
+            return result;
+        }
 
+        let mut state = ROOT;
         for (i, c) in text.chars().enumerate() {
             let c = if self.case_insensitive {
                 c.to_ascii_lowercase()
@@ -155,32 +205,54 @@ impl Node {
                 c
             };
 
-            while node.children.get(&c).is_none() {
-                if node.fail.is_none() {
-                    node = self;
-                    break;
+            state = loop {
+                if let Some(next) = self.states[state].next(c) {
+                    break next;
                 }
+                if state == ROOT {
+                    break ROOT;
+                }
+                state = self.states[state].fail as usize;
+            };
 
-                node = node.fail.as_ref().unwrap();
-            }
-
-            if let Some(child) = node.children.get(&c) {
-                node = child;
-                for keyword in &node.keywords {
-                    let len = keyword.chars().count();
-                    let start = i - (len - 1);
-                    let end = start + len;
+            let mut output = if self.states[state].keyword.is_some() {
+                Some(state as u32)
+            } else {
+                self.states[state].output
+            };
+            while let Some(matched) = output {
+                let matched = &self.states[matched as usize];
+                if let Some(keyword) = matched.keyword {
+                    let (keyword, len) = &self.keywords[keyword as usize];
+                    let start = i + 1 - len;
 
                     result
                         .entry(keyword.clone())
                         .or_default()
-                        .push(Span::new(start, end));
+                        .push(Span::new(start, start + len));
                 }
+                output = matched.output;
             }
         }
 
         result
     }
+
+    fn tree_text(&self, state: usize) -> String {
+        let prefix = "|-";
+        let mut out = String::new();
+        for (c, child) in self.states[state].transitions.iter() {
+            out.push_str(&format!("{prefix}{c}\n"));
+
+            let child = *child as usize;
+            if !self.states[child].transitions.is_empty() {
+                let child_text = self.tree_text(child);
+                out.push_str(&format!("{prefix}{}\n", child_text.trim()));
+            }
+        }
+
+        out
+    }
 }
 
 #[cfg(test)]
@@ -260,4 +332,26 @@ mod tests {
             .collect()
         );
     }
+
+    #[test]
+    fn test_match_overlapped_keywords() {
+        let mut tree = Node::new(true);
+        tree.add_keywords(["he", "she", "his", "hers", "she"]);
+        tree.build();
+
+        let result = tree.match_keywords("uShers, this");
+        assert_eq!(
+            result,
+            vec![
+                ("she".to_string(), vec![Span::new(1, 4)]),
+                ("he".to_string(), vec![Span::new(2, 4)]),
+                ("hers".to_string(), vec![Span::new(2, 6)]),
+                ("his".to_string(), vec![Span::new(9, 12)]),
+            ]
+            .into_iter()
+            .collect()
+        );
+
+        assert!(Node::new(true).match_keywords("hello").is_empty());
+    }
 }
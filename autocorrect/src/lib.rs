@@ -33,8 +33,8 @@ fn main() {
     println!("{}", autocorrect::format("既に、世界中の数百という企業が Rust を採用し、高速で低リソースのクロスプラットフォームソリューションを実現しています。"));
     // => "既に、世界中の数百という企業が Rust を採用し、高速で低リソースのクロスプラットフォームソリューションを実現しています。"
 
-    println!("{}", autocorrect::format("전 세계 수백 개의 회사가 프로덕션 환경에서 Rust 를 사용하여 빠르고， 크로스 플랫폼 및 낮은 리소스 사용량을 달성했습니다."));
-    // => "전 세계 수백 개의 회사가 프로덕션 환경에서 Rust 를 사용하여 빠르고， 크로스 플랫폼 및 낮은 리소스 사용량을 달성했습니다."
+    println!("{}", autocorrect::format("전 세계 수백 개의 회사가 프로덕션 환경에서Rust를 사용하여 빠르고，크로스 플랫폼 및 낮은 리소스 사용량을 달성했습니다."));
+    // => "전 세계 수백 개의 회사가 프로덕션 환경에서 Rust를 사용하여 빠르고, 크로스 플랫폼 및 낮은 리소스 사용량을 달성했습니다."
 }
 ```
 */
@@ -147,7 +147,9 @@ mod serde_any;
 pub mod config;
 pub mod ignorer;
 
-pub use code::{format_for, get_file_extension, is_support_type, lint_for};
+pub use code::{
+    format_for, get_file_extension, is_support_type, lint_for, lint_for_with_directives,
+};
 pub use config::Config;
 pub use format::*;
 pub use result::{json, rdjson, FormatResult, LineResult, LintResult, Severity};
@@ -28,6 +28,8 @@ pub(crate) fn crate_test_lint_results() -> Vec<LintResult> {
         new: "hello 你好。".to_owned(),
         old: "hello你好.".to_owned(),
         severity: Severity::Error,
+        message: String::new(),
+        id: String::new(),
     });
     lint_result.push(LineResult {
         line: 2,
@@ -35,6 +37,8 @@ pub(crate) fn crate_test_lint_results() -> Vec<LintResult> {
         new: "这是第 2 行".to_owned(),
         old: "这是第2行".to_owned(),
         severity: Severity::Error,
+        message: String::new(),
+        id: String::new(),
     });
 
     vec![lint_result]
@@ -42,6 +42,19 @@ pub struct LineResult {
     pub new: String,
     pub old: String,
     pub severity: Severity,
+    /// Addition message to describe the problem
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub message: String,
+    /// The id of the unit in file, e.g.: the `trans-unit` id in XLIFF
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+}
+
+impl LineResult {
+    /// The result only reports a message without any change, e.g.: a warning of unused directive.
+    pub fn is_message_only(&self) -> bool {
+        self.old == self.new
+    }
 }
 
 pub trait Results {
@@ -50,38 +63,35 @@ pub trait Results {
     fn error(&mut self, err: &str);
     fn to_string(&self) -> String;
     fn is_lint(&self) -> bool;
-    fn get_toggle(&self) -> toggle::Toggle;
-    fn set_toggle(&mut self, t: toggle::Toggle);
+    fn get_toggle(&self) -> &toggle::ToggleStack;
+    fn toggle_mut(&mut self) -> &mut toggle::ToggleStack;
+    /// The file type in processing, for the `scopes` config.
+    fn file_type(&self) -> &str;
+    fn set_file_type(&mut self, file_type: &str);
+    /// The path of the document in processing, for the relative paths in front matter config.
+    fn filepath(&self) -> &str;
+    fn set_filepath(&mut self, filepath: &str);
 
     /// Move and save current line,col return the previus line number
     fn move_cursor(&mut self, part: &str) -> (usize, usize);
 
-    /// Toggle AutoCorrrect template enable or disable
-    /// If new toggle is None, ignore
-    fn toggle(&mut self, new_toggle: toggle::Toggle) {
-        if new_toggle == toggle::Toggle::None {
-            return;
-        }
+    /// Set the id of the unit in processing, the lint results will be reported with it.
+    fn set_unit_id(&mut self, _id: &str) {}
 
-        self.set_toggle(new_toggle);
+    /// The toggle directives collector, only Lint will collect them.
+    fn directives_mut(&mut self) -> Option<&mut toggle::Directives> {
+        None
     }
 
-    fn toggle_merge(&mut self, new_toggle: toggle::Toggle) {
-        if new_toggle == toggle::Toggle::None {
-            return;
-        }
-
-        let mut toggle = self.get_toggle();
-        toggle.merge(new_toggle);
-        self.set_toggle(toggle);
+    /// Toggle AutoCorrrect enable or disable by a directive,
+    /// return the directive indexes of the closed frames.
+    fn toggle(&mut self, new_toggle: toggle::Toggle, directive: Option<usize>) -> Vec<usize> {
+        self.toggle_mut().apply(new_toggle, directive)
     }
 
     /// Is AutoCorrrect current is enable
     fn is_enabled(&self) -> bool {
-        match self.get_toggle().match_rule("") {
-            Some(enable) => enable,
-            _ => true,
-        }
+        self.get_toggle().is_enabled()
     }
 }
 
@@ -94,7 +104,11 @@ pub struct FormatResult {
     #[serde(skip)]
     pub enable: bool,
     #[serde(skip)]
-    pub toggle: toggle::Toggle,
+    pub toggle: toggle::ToggleStack,
+    #[serde(skip)]
+    pub file_type: String,
+    #[serde(skip)]
+    pub filepath: String,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -107,13 +121,21 @@ pub struct LintResult {
     #[serde(skip)]
     pub enable: bool,
     #[serde(skip)]
-    pub toggle: toggle::Toggle,
+    pub toggle: toggle::ToggleStack,
+    /// The toggle directives, only collected when `report_directives` is required.
+    #[serde(skip)]
+    pub directives: Option<toggle::Directives>,
+    #[serde(skip)]
+    pub file_type: String,
     // For store line number in loop
     #[serde(skip)]
     line: usize,
     // For store col number in loop
     #[serde(skip)]
     col: usize,
+    // For store the unit id in loop
+    #[serde(skip)]
+    unit_id: String,
 }
 
 impl FormatResult {
@@ -123,7 +145,9 @@ impl FormatResult {
             out: String::from(""),
             error: String::from(""),
             enable: true,
-            toggle: toggle::Toggle::default(),
+            toggle: toggle::ToggleStack::default(),
+            file_type: String::new(),
+            filepath: String::new(),
         }
     }
 
@@ -135,11 +159,18 @@ impl FormatResult {
 
 impl Results for FormatResult {
     fn push(&mut self, line_result: LineResult) {
+        // The out has been reverted to raw by error
+        if self.has_error() {
+            return;
+        }
+
         self.out.push_str(line_result.new.as_str());
     }
 
     fn ignore(&mut self, part: &str) {
-        self.out.push_str(part);
+        if !self.has_error() {
+            self.out.push_str(part);
+        }
         self.move_cursor(part);
     }
 
@@ -157,12 +188,28 @@ impl Results for FormatResult {
         false
     }
 
-    fn set_toggle(&mut self, t: toggle::Toggle) {
-        self.toggle = t
+    fn get_toggle(&self) -> &toggle::ToggleStack {
+        &self.toggle
     }
 
-    fn get_toggle(&self) -> toggle::Toggle {
-        self.toggle.clone()
+    fn toggle_mut(&mut self) -> &mut toggle::ToggleStack {
+        &mut self.toggle
+    }
+
+    fn file_type(&self) -> &str {
+        &self.file_type
+    }
+
+    fn set_file_type(&mut self, file_type: &str) {
+        self.file_type = file_type.to_owned();
+    }
+
+    fn filepath(&self) -> &str {
+        &self.filepath
+    }
+
+    fn set_filepath(&mut self, filepath: &str) {
+        self.filepath = filepath.to_owned();
     }
 
     fn move_cursor(&mut self, _part: &str) -> (usize, usize) {
@@ -180,7 +227,10 @@ impl LintResult {
             lines: Vec::new(),
             error: String::from(""),
             enable: true,
-            toggle: toggle::Toggle::default(),
+            toggle: toggle::ToggleStack::default(),
+            directives: None,
+            file_type: String::new(),
+            unit_id: String::new(),
         }
     }
 
@@ -206,7 +256,20 @@ impl LintResult {
         let filepath = self.filepath.replace("./", "");
 
         for line in self.lines.iter() {
-            out.push_str(&format!("{}:{}:{}\n", filepath, line.line, line.col));
+            out.push_str(&format!("{}:{}:{}", filepath, line.line, line.col));
+            if !line.id.is_empty() {
+                out.push_str(&format!(" ({})", line.id));
+            }
+            out.push('\n');
+            if !line.message.is_empty() {
+                out.push_str(&format!("{}\n", line.message));
+            }
+
+            // Nothing to diff, just print the source for the message
+            if line.is_message_only() {
+                out.push_str(&format!("{}\n", line.old.trim()));
+                continue;
+            }
 
             let out_str = crate::diff::diff_line_result(line, no_diff_bg_color);
             out.push_str(&out_str);
@@ -232,10 +295,96 @@ impl LintResult {
             .filter(|l| l.severity.is_warning())
             .count()
     }
+
+    /// Report the problems of toggle directives as warnings:
+    ///
+    /// - `autocorrect-disable` that suppressed nothing.
+    /// - Unknown rule names in directives, e.g.: `autocorrect-disable spaceword`.
+    /// - `autocorrect-disable` that never closed by `autocorrect-enable`,
+    ///   except the leading one for disable the whole file.
+    pub fn report_directives(&mut self) {
+        let rule_names = crate::rule::default_rule_names();
+        let Some(directives) = self.directives.as_ref() else {
+            return;
+        };
+
+        for directive in directives.items.iter() {
+            for name in directive.toggle.rule_names() {
+                if rule_names.contains(name) {
+                    continue;
+                }
+
+                let mut message = format!("Unknown rule `{name}` in directive.");
+                let mut new = directive.raw.clone();
+                if let Some(suggestion) = rule_names
+                    .iter()
+                    .find(|r| r.replace('-', "") == name.replace(['-', '_'], ""))
+                {
+                    message.push_str(&format!(" Did you mean `{suggestion}`?"));
+                    new = replace_rule_name(&directive.raw, name, suggestion);
+                }
+
+                self.lines.push(LineResult {
+                    line: directive.line,
+                    col: directive.col,
+                    old: directive.raw.clone(),
+                    new,
+                    severity: Severity::Warning,
+                    message,
+                    id: String::new(),
+                });
+            }
+
+            if !matches!(directive.toggle, toggle::Toggle::Disable(_)) {
+                continue;
+            }
+
+            if !directive.used {
+                self.lines.push(LineResult {
+                    line: directive.line,
+                    col: directive.col,
+                    old: directive.raw.clone(),
+                    new: directive.raw.clone(),
+                    severity: Severity::Warning,
+                    message: "Unused directive, nothing was suppressed.".to_string(),
+                    id: String::new(),
+                });
+            } else if !directive.closed && !directive.leading {
+                self.lines.push(LineResult {
+                    line: directive.line,
+                    col: directive.col,
+                    old: directive.raw.clone(),
+                    new: directive.raw.clone(),
+                    severity: Severity::Warning,
+                    message:
+                        "Unclosed directive, add `autocorrect-enable` after the disabled content."
+                            .to_string(),
+                    id: String::new(),
+                });
+            }
+        }
+
+        self.lines.sort_by_key(|l| (l.line, l.col));
+    }
+}
+
+/// Replace a lowercased rule name in a directive with the new rule name.
+fn replace_rule_name(raw: &str, name: &str, new_name: &str) -> String {
+    let lower = raw.to_lowercase();
+    match lower.find(name) {
+        // The lowercase of ASCII rule name has the same byte offset.
+        Some(idx) if raw.is_char_boundary(idx) && lower.len() == raw.len() => {
+            format!("{}{}{}", &raw[..idx], new_name, &raw[idx + name.len()..])
+        }
+        _ => raw.to_string(),
+    }
 }
 
 impl Results for LintResult {
-    fn push(&mut self, line_result: LineResult) {
+    fn push(&mut self, mut line_result: LineResult) {
+        if line_result.id.is_empty() {
+            line_result.id = self.unit_id.clone();
+        }
         self.lines.push(line_result);
     }
 
@@ -256,12 +405,36 @@ impl Results for LintResult {
         true
     }
 
-    fn set_toggle(&mut self, t: toggle::Toggle) {
-        self.toggle = t
+    fn get_toggle(&self) -> &toggle::ToggleStack {
+        &self.toggle
+    }
+
+    fn toggle_mut(&mut self) -> &mut toggle::ToggleStack {
+        &mut self.toggle
+    }
+
+    fn file_type(&self) -> &str {
+        &self.file_type
+    }
+
+    fn set_file_type(&mut self, file_type: &str) {
+        self.file_type = file_type.to_owned();
+    }
+
+    fn filepath(&self) -> &str {
+        &self.filepath
     }
 
-    fn get_toggle(&self) -> toggle::Toggle {
-        self.toggle.clone()
+    fn set_filepath(&mut self, filepath: &str) {
+        self.filepath = filepath.to_owned();
+    }
+
+    fn set_unit_id(&mut self, id: &str) {
+        self.unit_id = id.to_owned();
+    }
+
+    fn directives_mut(&mut self) -> Option<&mut toggle::Directives> {
+        self.directives.as_mut()
     }
 
     /// Move the (line, col) with string part
@@ -334,6 +507,26 @@ mod tests {
         assert!(!Severity::Warning.is_error());
     }
 
+    #[test]
+    fn test_to_diff_message_only() {
+        let mut result = LintResult::new("");
+        result.filepath = "./foo.js".to_owned();
+        result.push(LineResult {
+            line: 2,
+            col: 1,
+            old: "// autocorrect-disable".to_owned(),
+            new: "// autocorrect-disable".to_owned(),
+            severity: Severity::Warning,
+            message: "Unused directive, nothing was suppressed.".to_owned(),
+            id: String::new(),
+        });
+
+        assert_eq!(
+            "foo.js:2:1\nUnused directive, nothing was suppressed.\n// autocorrect-disable\n",
+            result.to_diff(true)
+        );
+    }
+
     #[test]
     fn test_move_cursor() {
         let mut out = LintResult::new("");
@@ -82,7 +82,7 @@ pub(crate) fn to_rdjson_diagnostics(lint_result: &LintResult) -> Vec<String> {
         };
 
         let rdf_diagnostic: RdfDiagnostic = RdfDiagnostic {
-            message: "".to_owned(),
+            message: line_result.message.clone(),
             location: RdfLocation {
                 path: lint_result.filepath.replace("./", ""),
                 range: RdfRange {
@@ -95,13 +95,17 @@ pub(crate) fn to_rdjson_diagnostics(lint_result: &LintResult) -> Vec<String> {
                 value: Some("AutoCorrect".to_owned()),
                 url: "https://github.com/huacnlee/autocorrect".to_owned(),
             },
-            suggestions: vec![RdfSuggetion {
-                text: line_result.new.clone(),
-                range: RdfRange {
-                    start: Some(start),
-                    end: Some(end),
-                },
-            }],
+            suggestions: if line_result.is_message_only() {
+                vec![]
+            } else {
+                vec![RdfSuggetion {
+                    text: line_result.new.clone(),
+                    range: RdfRange {
+                        start: Some(start),
+                        end: Some(end),
+                    },
+                }]
+            },
         };
 
         diagnostics.push(serde_json::to_string(&rdf_diagnostic).unwrap())
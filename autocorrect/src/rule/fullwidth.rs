@@ -2,8 +2,10 @@
 use regex::Regex;
 use std::collections::HashMap;
 
-const SPCIAL_PUNCTUATIONS: &str = "[.:!]([ ]*)";
-const NORMAL_PUNCTUATIONS: &str = "[,?]([ ]*)";
+use crate::config::Config;
+
+const SPCIAL_PUNCTUATIONS: &str = ".:!";
+const NORMAL_PUNCTUATIONS: &str = ",?";
 
 lazy_static! {
     static ref FULLWIDTH_MAPS: HashMap<&'static str, &'static str> = map!(
@@ -14,60 +16,121 @@ lazy_static! {
       "!" => "！",
       "?" => "？"
     );
-    static ref PUNCTUATION_WITH_LEFT_CJK_RE: Regex = regexp!(
-        "{}{}{}",
-        r"[\p{CJ}\w\d]+",
-        NORMAL_PUNCTUATIONS,
-        r"[\p{CJ}]+"
-    );
-    static ref PUNCTUATION_WITH_RIGHT_CJK_RE: Regex =
-        regexp!("{}{}", r"[\p{CJ}]+", NORMAL_PUNCTUATIONS);
-    static ref PUNCTUATION_WITH_SPEICAL_CJK_RE: Regex =
-        regexp!("{}{}{}", r"[\p{CJ}]+", SPCIAL_PUNCTUATIONS, r"[\p{CJ}]+");
-    static ref PUNCTUATION_WITH_SPEICAL_LAST_CJK_RE: Regex =
-        regexp!("{}{}{}", r"[\p{CJ}]+", SPCIAL_PUNCTUATIONS, r#"["']?$"#);
-    static ref PUNCTUATIONS_RE: Regex =
-        regexp!("({}|{})", SPCIAL_PUNCTUATIONS, NORMAL_PUNCTUATIONS);
+    static ref DEFAULT_PUNCTUATIONS: Punctuations = Punctuations::new(&HashMap::new());
 }
 
-// fullwidth correct punctuations near the CJK chars
-pub fn format(text: &str) -> String {
-    let mut out = String::from(text);
-
-    out = PUNCTUATION_WITH_LEFT_CJK_RE
-        .replace_all(&out, |cap: &regex::Captures| {
-            fullwidth_replace_part(&cap[0])
-        })
-        .to_string();
-
-    out = PUNCTUATION_WITH_RIGHT_CJK_RE
-        .replace_all(&out, |cap: &regex::Captures| {
-            fullwidth_replace_part(&cap[0])
-        })
-        .to_string();
-
-    out = PUNCTUATION_WITH_SPEICAL_CJK_RE
-        .replace_all(&out, |cap: &regex::Captures| {
-            fullwidth_replace_part(&cap[0])
-        })
-        .to_string();
-
-    out = PUNCTUATION_WITH_SPEICAL_LAST_CJK_RE
-        .replace_all(&out, |cap: &regex::Captures| {
-            fullwidth_replace_part(&cap[0])
-        })
-        .to_string();
-
-    out
+/// The punctuations to convert to fullwidth, with the `widthMaps.fullwidth` config applied.
+///
+/// The extra punctuations in config are only converted between the CJK chars (like `.:!`),
+/// the empty value in config disables the conversion of the punctuation.
+#[derive(Clone, Debug)]
+pub(crate) struct Punctuations {
+    map: HashMap<String, String>,
+    /// The regexps to match the parts near the CJK chars, in order.
+    part_res: Vec<Regex>,
+    punctuations_re: Option<Regex>,
 }
 
-fn fullwidth_replace_part(part: &str) -> String {
-    let out = PUNCTUATIONS_RE.replace_all(part, |cap: &regex::Captures| {
-        let str = &cap[0];
-        return FULLWIDTH_MAPS[String::from(str).trim()];
-    });
+impl Punctuations {
+    pub fn new(overrides: &HashMap<String, String>) -> Self {
+        let mut map: HashMap<String, String> = FULLWIDTH_MAPS
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let mut special = SPCIAL_PUNCTUATIONS.chars().collect::<Vec<_>>();
+        let mut normal = NORMAL_PUNCTUATIONS.chars().collect::<Vec<_>>();
+
+        for (from, to) in overrides {
+            let Some(c) = from.chars().next() else {
+                continue;
+            };
+
+            if to.is_empty() {
+                map.remove(from);
+                special.retain(|p| *p != c);
+                normal.retain(|p| *p != c);
+            } else {
+                map.insert(from.clone(), to.clone());
+                if !special.contains(&c) && !normal.contains(&c) {
+                    special.push(c);
+                }
+            }
+        }
+
+        let class = |chars: &[char]| {
+            let chars = chars.iter().collect::<String>();
+            format!("[{}]([ ]*)", regex::escape(&chars))
+        };
+
+        let mut part_res = vec![];
+        if !normal.is_empty() {
+            let normal = class(&normal);
+            part_res.push(regexp!("{}{}{}", r"[\p{CJ}\w\d]+", normal, r"[\p{CJ}]+"));
+            part_res.push(regexp!("{}{}", r"[\p{CJ}]+", normal));
+        }
+        if !special.is_empty() {
+            let special = class(&special);
+            part_res.push(regexp!("{}{}{}", r"[\p{CJ}]+", special, r"[\p{CJ}]+"));
+            part_res.push(regexp!("{}{}{}", r"[\p{CJ}]+", special, r#"["']?$"#));
+        }
+
+        let punctuations_re = if part_res.is_empty() {
+            None
+        } else {
+            let all = special
+                .iter()
+                .chain(normal.iter())
+                .copied()
+                .collect::<Vec<_>>();
+            Some(regexp!("{}", class(&all)))
+        };
+
+        Punctuations {
+            map,
+            part_res,
+            punctuations_re,
+        }
+    }
+
+    /// The punctuations without any config.
+    pub fn default_ref() -> &'static Self {
+        &DEFAULT_PUNCTUATIONS
+    }
 
-    out.to_string()
+    fn format(&self, text: &str) -> String {
+        let mut out = String::from(text);
+
+        for re in self.part_res.iter() {
+            out = re
+                .replace_all(&out, |cap: &regex::Captures| self.replace_part(&cap[0]))
+                .to_string();
+        }
+
+        out
+    }
+
+    fn replace_part(&self, part: &str) -> String {
+        let Some(punctuations_re) = &self.punctuations_re else {
+            return part.to_string();
+        };
+
+        let out = punctuations_re.replace_all(part, |cap: &regex::Captures| {
+            let str = &cap[0];
+            self.map
+                .get(str.trim())
+                .cloned()
+                .unwrap_or_else(|| str.to_string())
+        });
+
+        out.to_string()
+    }
+}
+
+// fullwidth correct punctuations near the CJK chars
+pub fn format(text: &str) -> String {
+    let config = Config::effective();
+
+    config.width_maps.fullwidth_punctuations().format(text)
 }
 
 #[cfg(test)]
@@ -115,4 +178,25 @@ mod tests {
 
         assert_cases(cases);
     }
+
+    #[test]
+    fn test_fullwidth_with_width_maps() {
+        let config = Config::from_str(indoc::indoc! {r#"
+        widthMaps:
+          fullwidth:
+            ";": "；"
+            ":": ""
+        "#})
+        .unwrap();
+        let last_config = crate::config::set_document_config(Some(&config)).unwrap();
+
+        let cases = map!(
+            "中文;中文" => "中文；中文",
+            "蚂蚁疾奔:蚂蚁集团两地上市,全速推进!" => "蚂蚁疾奔:蚂蚁集团两地上市，全速推进！",
+            "Hello;world" => "Hello;world"
+        );
+        assert_cases(cases);
+
+        crate::config::restore_document_config(last_config);
+    }
 }
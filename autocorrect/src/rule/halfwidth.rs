@@ -2,7 +2,8 @@
 use regex::Regex;
 use std::collections::HashMap;
 
-use super::CJK_RE;
+use super::{lang, CJK_RE};
+use crate::config::Config;
 
 #[derive(Clone)]
 enum ReplaceMode {
@@ -93,6 +94,16 @@ lazy_static! {
         "」" => ReplaceRule::new("]").right_quote().with_suffix_space(),
         "》" => ReplaceRule::new("”").right_quote().with_suffix_space(),
     );
+
+    // Korean uses the halfwidth punctuations, followed by a space, e.g.: `빠르고, 크로스`
+    static ref KOREAN_PUNCTUATION_MAP: HashMap<char, char> = map!(
+        '，' => ',',
+        '。' => '.',
+        '：' => ':',
+        '；' => ';',
+        '！' => '!',
+        '？' => '?',
+    );
 }
 
 #[allow(dead_code)]
@@ -133,9 +144,20 @@ pub fn format_punctuation(text: &str) -> String {
 }
 
 pub fn format_word(text: &str) -> String {
+    let config = Config::effective();
     let mut out = String::new();
 
     for part in text.split("") {
+        // The `widthMaps.halfwidth` config has higher priority, empty to keep the char.
+        if let Some(new_str) = config.width_maps.get_halfwidth(part) {
+            if new_str.is_empty() {
+                out.push_str(part);
+            } else {
+                out.push_str(new_str);
+            }
+            continue;
+        }
+
         if let Some(new_str) = CHAR_WIDTH_MAP.get(part) {
             out.push_str(new_str);
             continue;
@@ -153,10 +175,6 @@ pub fn format_word(text: &str) -> String {
 }
 
 fn is_may_only_english(text: &str) -> bool {
-    if CJK_RE.is_match(text) {
-        return false;
-    }
-
     // Characters which pass CHAR_WIDTH_MAP replacement
     if ENGLISH_RE.is_match(text) && START_WITH_WORD_RE.is_match(text) {
         // Maybe English, pass
@@ -178,6 +196,50 @@ fn is_may_only_english(text: &str) -> bool {
 }
 
 fn format_line(text: &str, wrap_quote: char) -> String {
+    // Only format the English sentences in the line with CJK, the CJK sentences are left to `fullwidth`
+    if CJK_RE.is_match(text) {
+        return lang::sentences(text)
+            .into_iter()
+            .map(|sentence| match sentence.lang {
+                lang::Lang::English => format_sentence(sentence.text, wrap_quote),
+                lang::Lang::Korean => {
+                    let next = text[sentence.start + sentence.text.len()..].chars().next();
+                    format_korean_sentence(sentence.text, next)
+                }
+                _ => sentence.text.to_string(),
+            })
+            .collect();
+    }
+
+    format_sentence(text, wrap_quote)
+}
+
+/// Convert the fullwidth punctuations in Korean sentence, the `next` is the char after the sentence.
+fn format_korean_sentence(text: &str, next: Option<char>) -> String {
+    let mut out = String::with_capacity(text.len());
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let Some(to) = KOREAN_PUNCTUATION_MAP.get(&c) else {
+            out.push(c);
+            continue;
+        };
+
+        out.push(*to);
+        if chars
+            .peek()
+            .copied()
+            .or(next)
+            .is_some_and(|n| n.is_alphanumeric())
+        {
+            out.push(' ');
+        }
+    }
+
+    out
+}
+
+fn format_sentence(text: &str, wrap_quote: char) -> String {
     if !is_may_only_english(text) {
         return String::from(text);
     }
@@ -278,6 +340,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_halfwidth_word_with_width_maps() {
+        let config = Config::from_str(indoc::indoc! {r#"
+        widthMaps:
+          halfwidth:
+            "～": "~"
+            "＠": "@"
+            "　": ""
+        "#})
+        .unwrap();
+        let last_config = crate::config::set_document_config(Some(&config)).unwrap();
+
+        assert_eq!(
+            "联系huacnlee@gmail~",
+            format_word("联系ｈｕａｃｎｌｅｅ＠gmail～")
+        );
+        assert_eq!("后场　200 店舗", format_word("后场　２００ 店舗"));
+
+        crate::config::restore_document_config(last_config);
+    }
+
     #[test]
     fn test_halfwidth_punctuation_ignores() {
         let cases = map! [
@@ -323,6 +406,19 @@ mod tests {
         assert_cases(cases);
     }
 
+    #[test]
+    fn test_halfwidth_punctuation_in_korean() {
+        let cases = map! [
+            "빠르고，크로스 플랫폼" => "빠르고, 크로스 플랫폼",
+            "빠르고， 크로스 플랫폼" => "빠르고, 크로스 플랫폼",
+            "Rust를 사용합니다。다음：설치！" => "Rust를 사용합니다. 다음: 설치!",
+            "정말？" => "정말?",
+            "中文，한국어" => "中文，한국어",
+        ];
+
+        assert_cases(cases);
+    }
+
     #[test]
     fn test_ignore_left_quote_in_last() {
         let cases = map! [
@@ -0,0 +1,71 @@
+// autocorrect: false
+use std::collections::HashMap;
+
+lazy_static! {
+    static ref KATAKANA_MAP: HashMap<char, char> = map!(
+      'ｦ' => 'ヲ', 'ｧ' => 'ァ', 'ｨ' => 'ィ', 'ｩ' => 'ゥ', 'ｪ' => 'ェ', 'ｫ' => 'ォ', 'ｬ' => 'ャ', 'ｭ' => 'ュ', 'ｮ' => 'ョ', 'ｯ' => 'ッ', 'ｰ' => 'ー',
+      'ｱ' => 'ア', 'ｲ' => 'イ', 'ｳ' => 'ウ', 'ｴ' => 'エ', 'ｵ' => 'オ', 'ｶ' => 'カ', 'ｷ' => 'キ', 'ｸ' => 'ク', 'ｹ' => 'ケ', 'ｺ' => 'コ',
+      'ｻ' => 'サ', 'ｼ' => 'シ', 'ｽ' => 'ス', 'ｾ' => 'セ', 'ｿ' => 'ソ', 'ﾀ' => 'タ', 'ﾁ' => 'チ', 'ﾂ' => 'ツ', 'ﾃ' => 'テ', 'ﾄ' => 'ト',
+      'ﾅ' => 'ナ', 'ﾆ' => 'ニ', 'ﾇ' => 'ヌ', 'ﾈ' => 'ネ', 'ﾉ' => 'ノ', 'ﾊ' => 'ハ', 'ﾋ' => 'ヒ', 'ﾌ' => 'フ', 'ﾍ' => 'ヘ', 'ﾎ' => 'ホ',
+      'ﾏ' => 'マ', 'ﾐ' => 'ミ', 'ﾑ' => 'ム', 'ﾒ' => 'メ', 'ﾓ' => 'モ', 'ﾔ' => 'ヤ', 'ﾕ' => 'ユ', 'ﾖ' => 'ヨ',
+      'ﾗ' => 'ラ', 'ﾘ' => 'リ', 'ﾙ' => 'ル', 'ﾚ' => 'レ', 'ﾛ' => 'ロ', 'ﾜ' => 'ワ', 'ﾝ' => 'ン', 'ﾞ' => '゛', 'ﾟ' => '゜',
+      '｡' => '。', '｢' => '「', '｣' => '」', '､' => '、', '･' => '・',
+    );
+
+    // The katakana with the (semi-)voiced sound mark, e.g.: ｶﾞ -> ガ, ﾊﾟ -> パ
+    static ref VOICED_KATAKANA_MAP: HashMap<&'static str, char> = map!(
+      "ｳﾞ" => 'ヴ', "ｶﾞ" => 'ガ', "ｷﾞ" => 'ギ', "ｸﾞ" => 'グ', "ｹﾞ" => 'ゲ', "ｺﾞ" => 'ゴ',
+      "ｻﾞ" => 'ザ', "ｼﾞ" => 'ジ', "ｽﾞ" => 'ズ', "ｾﾞ" => 'ゼ', "ｿﾞ" => 'ゾ', "ﾀﾞ" => 'ダ', "ﾁﾞ" => 'ヂ', "ﾂﾞ" => 'ヅ', "ﾃﾞ" => 'デ', "ﾄﾞ" => 'ド',
+      "ﾊﾞ" => 'バ', "ﾋﾞ" => 'ビ', "ﾌﾞ" => 'ブ', "ﾍﾞ" => 'ベ', "ﾎﾞ" => 'ボ', "ﾜﾞ" => 'ヷ', "ｦﾞ" => 'ヺ',
+      "ﾊﾟ" => 'パ', "ﾋﾟ" => 'ピ', "ﾌﾟ" => 'プ', "ﾍﾟ" => 'ペ', "ﾎﾟ" => 'ポ',
+    );
+}
+
+/// Halfwidth katakana to fullwidth katakana, e.g.: `ｶﾞｲﾄﾞ` -> `ガイド`
+pub fn format(text: &str) -> String {
+    // Fast path, no halfwidth katakana
+    if !text.contains(|c| ('\u{ff61}'..='\u{ff9f}').contains(&c)) {
+        return text.to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if let Some(mark) = chars.next_if(|next| *next == 'ﾞ' || *next == 'ﾟ') {
+            let pair = format!("{c}{mark}");
+            if let Some(new_char) = VOICED_KATAKANA_MAP.get(pair.as_str()) {
+                out.push(*new_char);
+                continue;
+            }
+
+            out.push(*KATAKANA_MAP.get(&c).unwrap_or(&c));
+            out.push(KATAKANA_MAP[&mark]);
+            continue;
+        }
+
+        out.push(*KATAKANA_MAP.get(&c).unwrap_or(&c));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let cases = map!(
+            "ｶﾞｲﾄﾞ" => "ガイド",
+            "ﾊﾟｿｺﾝのｿﾌﾄｳｪｱ" => "パソコンのソフトウェア",
+            "ｳﾞｧｲｵﾘﾝ｡" => "ヴァイオリン。",
+            "｢ﾃﾞｰﾀﾍﾞｰｽ｣､ｻｰﾊﾞｰ･ｱﾌﾟﾘ" => "「データベース」、サーバー・アプリ",
+            "ｱﾞ" => "ア゛",
+            "ガイド Hello 你好" => "ガイド Hello 你好"
+        );
+
+        for (source, expected) in cases {
+            assert_eq!(expected, format(source), "{source}");
+        }
+    }
+}
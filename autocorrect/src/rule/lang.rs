@@ -0,0 +1,218 @@
+// autocorrect: false
+//! Detect the language of the sentences by the script ratio.
+//!
+//! This is shared by the `fullwidth` and `halfwidth-punctuation` rules,
+//! the punctuations in CJK sentence only for `fullwidth`, and in English or Korean sentence only for `halfwidth-punctuation`.
+use super::CJK_RE;
+
+lazy_static! {
+    static ref HANGUL_RE: regex::Regex = regexp!(r"\p{Hangul}");
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Lang {
+    Cjk,
+    /// The CJK chars are all Hangul, the Korean uses the halfwidth punctuations.
+    Korean,
+    English,
+    /// No CJK chars or English words, e.g.: `123.`
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Sentence<'a> {
+    /// The byte index of the sentence start in the text.
+    pub start: usize,
+    pub text: &'a str,
+    pub lang: Lang,
+}
+
+/// Detect the language of a sentence by the ratio of CJK chars and English words.
+///
+/// The sentence is English only when the English words are more than the CJK chars,
+/// the URL or path is counted as one word, e.g.: `https://foo.com/bar`, `src/main.rs`.
+/// The sentence is Korean when all the CJK chars are Hangul.
+pub(crate) fn detect(text: &str) -> Lang {
+    let mut cjk_chars = 0;
+    let mut hangul_chars = 0;
+    let mut words = 0;
+    let mut in_word = false;
+
+    for c in text.chars() {
+        if c.is_ascii_alphabetic() {
+            if !in_word {
+                words += 1;
+                in_word = true;
+            }
+        } else if in_word && (c.is_ascii_digit() || "'-_./:@#?=&%+~".contains(c)) {
+            // Keep in the word, e.g.: HTML5, don't, e-mail, https://foo.com/bar
+        } else {
+            in_word = false;
+            if is_cjk(c) {
+                cjk_chars += 1;
+                if is_hangul(c) {
+                    hangul_chars += 1;
+                }
+            }
+        }
+    }
+
+    if cjk_chars == 0 && words == 0 {
+        return Lang::Unknown;
+    }
+
+    if words > cjk_chars {
+        Lang::English
+    } else if hangul_chars == cjk_chars {
+        Lang::Korean
+    } else {
+        Lang::Cjk
+    }
+}
+
+/// Split the text into sentences and detect the language of them.
+///
+/// The sentence ends with `。！？`, newline, or `.!?` followed by whitespace, the trailing whitespaces are included.
+/// The sentence without any words will follow the language of the previous (or next) sentence.
+pub(crate) fn sentences(text: &str) -> Vec<Sentence<'_>> {
+    let mut sentences = vec![];
+    let mut start = 0;
+
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let next = chars.peek().map(|(_, c)| *c);
+        let is_end = match c {
+            '。' | '！' | '？' | '\n' => true,
+            '.' | '!' | '?' => next.is_none_or(|n| n.is_whitespace()),
+            _ => false,
+        };
+        if !is_end {
+            continue;
+        }
+
+        // Include the trailing whitespaces, until the newline
+        let mut end = i + c.len_utf8();
+        let mut newline = c == '\n';
+        while let Some((j, n)) = chars.peek() {
+            if newline || !n.is_whitespace() {
+                break;
+            }
+            newline = *n == '\n';
+            end = j + n.len_utf8();
+            chars.next();
+        }
+
+        sentences.push(new_sentence(text, start, end));
+        start = end;
+    }
+
+    if start < text.len() {
+        sentences.push(new_sentence(text, start, text.len()));
+    }
+
+    // Follow the surrounding context
+    for i in 0..sentences.len() {
+        if sentences[i].lang != Lang::Unknown {
+            continue;
+        }
+
+        let context = sentences[..i]
+            .iter()
+            .rev()
+            .chain(sentences[i + 1..].iter())
+            .map(|s| s.lang)
+            .find(|lang| *lang != Lang::Unknown);
+        if let Some(lang) = context {
+            sentences[i].lang = lang;
+        }
+    }
+
+    sentences
+}
+
+/// Get the language of the sentence at the byte index.
+pub(crate) fn lang_at(sentences: &[Sentence], index: usize) -> Lang {
+    sentences
+        .iter()
+        .rev()
+        .find(|s| s.start <= index)
+        .map(|s| s.lang)
+        .unwrap_or(Lang::Unknown)
+}
+
+fn new_sentence(text: &str, start: usize, end: usize) -> Sentence<'_> {
+    let text = &text[start..end];
+    Sentence {
+        start,
+        text,
+        lang: detect(text),
+    }
+}
+
+fn is_cjk(c: char) -> bool {
+    let mut buf = [0; 4];
+    CJK_RE.is_match(c.encode_utf8(&mut buf))
+}
+
+fn is_hangul(c: char) -> bool {
+    let mut buf = [0; 4];
+    HANGUL_RE.is_match(c.encode_utf8(&mut buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        let cases = [
+            ("你好世界", Lang::Cjk),
+            ("Hello 世界", Lang::Cjk),
+            ("使用 GitHub Actions 部署", Lang::Cjk),
+            ("请在 Settings 中打开 Dark Mode。", Lang::Cjk),
+            ("Hello world", Lang::English),
+            (
+                "Fixed a bug in 登录，and improved the speed.",
+                Lang::English,
+            ),
+            ("Don't use HTML5 e-mail", Lang::English),
+            ("1. 在 Linux、macOS 和 Windows 上安装 Rust", Lang::Cjk),
+            (
+                "请访问：https://www.jetbrains.com/help/idea/using-git-integration.html。",
+                Lang::Cjk,
+            ),
+            ("こんにちは、World", Lang::Cjk),
+            ("Rust를 사용하여 빠르고，크로스 플랫폼", Lang::Korean),
+            ("韓國語 한국어", Lang::Cjk),
+            ("123.", Lang::Unknown),
+            ("", Lang::Unknown),
+        ];
+
+        for (text, lang) in cases {
+            assert_eq!(lang, detect(text), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_sentences() {
+        let text = "改进了性能。Fixed a crash in 登录，and more! 1.2.3 版本发布了.\n123. Hello";
+        let sentences = sentences(text);
+        assert_eq!(
+            vec![
+                ("改进了性能。", Lang::Cjk),
+                ("Fixed a crash in 登录，and more! ", Lang::English),
+                ("1.2.3 版本发布了.\n", Lang::Cjk),
+                ("123. ", Lang::Cjk),
+                ("Hello", Lang::English),
+            ],
+            sentences
+                .iter()
+                .map(|s| (s.text, s.lang))
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(Lang::Cjk, lang_at(&sentences, 0));
+        assert_eq!(Lang::English, lang_at(&sentences, sentences[1].start + 3));
+        assert_eq!(Lang::Unknown, lang_at(&[], 0));
+    }
+}
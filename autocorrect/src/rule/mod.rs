@@ -1,8 +1,11 @@
 // autocorrect: false
-mod fullwidth;
+pub(crate) mod fullwidth;
+mod katakana;
+mod lang;
 #[allow(clippy::module_inception)]
 mod rule;
 mod strategery;
+mod terms;
 mod word;
 
 pub mod halfwidth;
@@ -13,6 +16,7 @@ use std::collections::HashMap;
 use regex::Regex;
 use rule::{Rule, RuleResult};
 
+use crate::config::SpacePolicy;
 use crate::result::Severity;
 
 lazy_static! {
@@ -31,9 +35,20 @@ lazy_static! {
         Rule::new("fullwidth", fullwidth::format),
     ];
 
+    // The rules in `remove` space policy, run with the whole text,
+    // because the text is split into parts by spaces for `RULES`.
+    static ref REMOVE_SPACE_RULES: Vec<Rule> = vec![
+        Rule::new("space-word", word::remove_space_word),
+        Rule::new("space-bracket", word::remove_space_bracket),
+        Rule::new("space-dash", word::remove_space_dash),
+        Rule::new("space-backticks", word::remove_space_backticks),
+    ];
+
     static ref AFTER_RULES: Vec<Rule> = vec![
         // Rule: halfwidth-word
         Rule::new("halfwidth-word", halfwidth::format_word),
+        // Rule: fullwidth-katakana
+        Rule::new("fullwidth-katakana", katakana::format),
         // Rule: halfwidth punctuations
         Rule::new("halfwidth-punctuation", halfwidth::format_punctuation),
         // Rule: no-space-fullwidth
@@ -41,6 +56,10 @@ lazy_static! {
         // Rule: no-space-fullwidth-quote
         Rule::new("no-space-fullwidth-quote", word::format_no_space_fullwidth_quote),
         Rule::new("spellcheck", spellcheck::format),
+        // Rule: spellcheck-fuzzy, only suggest in lint
+        Rule::new("spellcheck-fuzzy", spellcheck::format_fuzzy).with_lint_only(),
+        // Rule: terms, the prohibited or discouraged terms in config
+        Rule::new("terms", terms::format).with_lint(terms::lint),
     ];
 }
 
@@ -90,8 +109,40 @@ pub(crate) fn format_or_lint_with_disable_rules(
 
     // skip if not has CJK
     if CJK_RE.is_match(text) {
+        // Release the config before the rules, they read the config again.
+        let remove_space_rules = {
+            let config = crate::Config::effective();
+            REMOVE_SPACE_RULES
+                .iter()
+                .filter(|r| !disable_rules.get(r.name.as_str()).unwrap_or(&false))
+                .filter(|r| config.space_policy(&r.name) == SpacePolicy::Remove)
+                .collect::<Vec<_>>()
+        };
+        let mut disable_rules = disable_rules.clone();
+        for rule in remove_space_rules.iter() {
+            disable_rules.insert(rule.name.clone(), true);
+        }
+        let disable_rules = &disable_rules;
+
+        // The punctuations in English sentences are left to `halfwidth-punctuation`
+        let sentences = if text.contains(|c: char| c.is_ascii_alphabetic()) {
+            lang::sentences(text)
+        } else {
+            vec![]
+        };
+        let mut english_disable_rules = disable_rules.clone();
+        english_disable_rules.insert("fullwidth".to_owned(), true);
+        let part_disable_rules = |part_start: usize| {
+            if lang::lang_at(&sentences, part_start) == lang::Lang::English {
+                &english_disable_rules
+            } else {
+                disable_rules
+            }
+        };
+
         let mut part = String::new();
-        for ch in text.chars() {
+        let mut part_start = 0;
+        for (i, ch) in text.char_indices() {
             part.push(ch);
 
             // Is next char is newline or space, break part to format
@@ -101,7 +152,8 @@ pub(crate) fn format_or_lint_with_disable_rules(
 
                 part.clear();
 
-                format_part(&mut sub_result, lint, disable_rules);
+                format_part(&mut sub_result, lint, part_disable_rules(part_start));
+                part_start = i + ch.len_utf8();
 
                 result.out.push_str(&sub_result.out);
                 result.severity = sub_result.severity;
@@ -112,11 +164,19 @@ pub(crate) fn format_or_lint_with_disable_rules(
             let mut sub_result = RuleResult::new(&part);
             sub_result.severity = result.severity;
 
-            format_part(&mut sub_result, lint, disable_rules);
+            format_part(&mut sub_result, lint, part_disable_rules(part_start));
 
             result.out.push_str(&sub_result.out);
             result.severity = sub_result.severity;
         }
+
+        for rule in remove_space_rules {
+            if lint {
+                rule.lint(&mut result);
+            } else {
+                rule.format(&mut result);
+            }
+        }
     } else {
         result.out = text.to_string();
     }
@@ -145,7 +205,7 @@ fn format_part(result: &mut RuleResult, lint: bool, disable_rules: &HashMap<Stri
     }
 
     // Check textRules to change result
-    for (text, mode) in crate::Config::current().text_rules.iter() {
+    for (text, mode) in crate::Config::effective().text_rules.iter() {
         if raw.contains(text) {
             match mode {
                 crate::config::SeverityMode::Off => {
@@ -206,10 +266,13 @@ mod tests {
             "space-backticks",
             "fullwidth",
             "halfwidth-word",
+            "fullwidth-katakana",
             "halfwidth-punctuation",
             "no-space-fullwidth",
             "no-space-fullwidth-quote",
             "spellcheck",
+            "spellcheck-fuzzy",
+            "terms",
         ];
         assert_eq!(expect, rule_names);
     }
@@ -269,6 +332,47 @@ mod tests {
         assert_eq!(Severity::Pass, result.severity);
     }
 
+    #[test]
+    fn test_format_mixed_sentences() {
+        crate::config::setup_test();
+
+        let result = format_or_lint(
+            "修复了若干问题,提升性能。Fixed a crash in 登录, and improved the speed，thanks!",
+            false,
+        );
+        assert_eq!(
+            "修复了若干问题，提升性能。Fixed a crash in 登录, and improved the speed, thanks!",
+            result.out
+        );
+    }
+
+    #[test]
+    fn test_format_with_remove_space_policy() {
+        crate::config::setup_test();
+
+        let config = crate::Config::from_str("spacePolicy: remove").unwrap();
+        let last_config = crate::config::set_document_config(Some(&config)).unwrap();
+
+        let cases = map! {
+            "Rust を使って Web アプリ（v2.0）を作る" => "Rustを使ってWebアプリ（v2.0）を作る",
+            "東京 - 大阪の `cargo` コマンド" => "東京-大阪の`cargo`コマンド",
+            "使用 Rust 开发 (beta) 版本" => "使用Rust开发(beta)版本",
+            "Hello world 你好" => "Hello world你好",
+            "Rust를 사용하여 개발" => "Rust를 사용하여 개발",
+            "日本語Rust" => "日本語Rust"
+        };
+        for (source, expected) in cases {
+            assert_eq!(expected, format_or_lint(source, false).out, "{source}");
+        }
+
+        let result = format_or_lint("Rust を使う", true);
+        assert_eq!(Severity::Error, result.severity);
+        let result = format_or_lint("Rustを使う", true);
+        assert_eq!(Severity::Pass, result.severity);
+
+        crate::config::restore_document_config(last_config);
+    }
+
     #[test]
     fn test_rules() {
         crate::config::setup_test();
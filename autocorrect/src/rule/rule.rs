@@ -5,12 +5,18 @@ pub(crate) struct Rule {
     #[allow(dead_code)]
     pub name: String,
     pub format_fn: fn(input: &str) -> String,
+    /// Only report in lint as warning, never change the text in format.
+    pub lint_only: bool,
+    /// Lint with the messages and severity of the problems, instead of `format_fn`, e.g.: `terms`
+    pub lint_fn: Option<fn(input: &str) -> RuleResult>,
 }
 
 #[derive(Default)]
 pub(crate) struct RuleResult {
     pub out: String,
     pub severity: Severity,
+    /// Messages to describe the problems, e.g.: from terms
+    pub messages: Vec<String>,
 }
 
 impl RuleResult {
@@ -27,11 +33,23 @@ impl Rule {
         Rule {
             name: name.to_string(),
             format_fn: format,
+            lint_only: false,
+            lint_fn: None,
         }
     }
 
+    pub fn with_lint_only(mut self) -> Self {
+        self.lint_only = true;
+        self
+    }
+
+    pub fn with_lint(mut self, lint: fn(input: &str) -> RuleResult) -> Self {
+        self.lint_fn = Some(lint);
+        self
+    }
+
     pub fn format(&self, result: &mut RuleResult) {
-        if self.severity() != SeverityMode::Error {
+        if self.lint_only || self.severity() != SeverityMode::Error {
             return;
         }
 
@@ -43,13 +61,28 @@ impl Rule {
     }
 
     pub fn lint(&self, result: &mut RuleResult) {
-        if self.severity() == SeverityMode::Off {
+        let severity = self.severity();
+        if severity == SeverityMode::Off {
+            return;
+        }
+
+        if let Some(lint_fn) = self.lint_fn {
+            let sub_result = lint_fn(&result.out);
+            if !sub_result.severity.is_pass() && result.severity == Severity::Pass {
+                if self.lint_only || severity == SeverityMode::Warning {
+                    result.severity = Severity::Warning;
+                } else {
+                    result.severity = sub_result.severity;
+                }
+            }
+            result.messages.extend(sub_result.messages);
+            result.out = sub_result.out;
             return;
         }
 
         let new = (self.format_fn)(&result.out);
         if result.out.ne(&new) && result.severity == Severity::Pass {
-            if self.severity() == SeverityMode::Warning {
+            if self.lint_only || self.severity() == SeverityMode::Warning {
                 result.severity = Severity::Warning;
             } else {
                 result.severity = Severity::Error;
@@ -59,7 +92,7 @@ impl Rule {
     }
 
     fn severity(&self) -> SeverityMode {
-        let config = crate::Config::current();
+        let config = crate::Config::effective();
 
         if let Some(s) = config.rules.get(&self.name) {
             s.clone()
@@ -90,6 +123,21 @@ mod tests {
         assert_eq!(result.severity, Severity::Error);
     }
 
+    #[test]
+    fn test_rule_lint_only() {
+        let rule = Rule::new("space-word", |input| format!("{input} - foo")).with_lint_only();
+
+        let mut result = RuleResult::new("test");
+        rule.format(&mut result);
+        assert_eq!(result.out, "test");
+        assert_eq!(result.severity, Severity::Pass);
+
+        let mut result = RuleResult::new("test");
+        rule.lint(&mut result);
+        assert_eq!(result.out, "test - foo");
+        assert_eq!(result.severity, Severity::Warning);
+    }
+
     #[test]
     fn test_rule_pass() {
         let rule = Rule::new("spellcheck", |input| input.to_string());
@@ -1,21 +1,211 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::{config::Config, keyword::MatchedResult};
+use crate::{
+    config::Config,
+    keyword::{MatchedResult, Span},
+    rule::CJK_RE,
+};
 
 lazy_static! {
     static ref DISALLOW_CHAR_RE: regex::Regex =
         regexp!("{}", r#"([^\p{Han}\s？！：，。；、「」“”‘’【】《》])"#);
+    static ref FUZZY_WORD_RE: regex::Regex = regexp!(r"[a-zA-Z][a-zA-Z0-9]*([\-.][a-zA-Z0-9]+)*");
 }
 
+/// The min length of the dictionary word to suggest by fuzzy match.
+const FUZZY_MIN_LEN: usize = 5;
+/// The dictionary word longer than this allows 2 edits, otherwise 1 edit.
+const FUZZY_LONG_LEN: usize = 9;
+
 // Spell check by dict
 pub fn format(text: &str) -> String {
-    let config = Config::current();
+    let config = Config::effective();
 
     let word_map = &config.spellcheck.word_map;
-    let matcher = &config.spellcheck.matcher;
+    let matcher = config.spellcheck.matcher();
 
     let matched_words = matcher.match_keywords(text);
-    replace_with_spans(text, &matched_words, word_map)
+    if matched_words.is_empty() {
+        return text.to_string();
+    }
+
+    let excluded_spans = config
+        .spellcheck
+        .exclude_matcher()
+        .match_keywords(text)
+        .into_values()
+        .flatten()
+        .collect::<Vec<_>>();
+    replace_with_spans(
+        text,
+        &matched_words,
+        word_map,
+        &config.spellcheck.cjk_keys,
+        &excluded_spans,
+    )
+}
+
+/// Suggest the dictionary word for the near-miss words, e.g.: `Javascirpt` -> `JavaScript`
+///
+/// This is used by `spellcheck-fuzzy` rule, only for lint.
+pub fn format_fuzzy(text: &str) -> String {
+    let config = Config::effective();
+
+    replace_with_suggestions(text, config.spellcheck.fuzzy_index())
+}
+
+/// The dictionary words indexed for the fuzzy match, it only builds once for a prepared config.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FuzzyIndex {
+    /// The lowercase of the dictionary words, to find the case-only differences, e.g.: `Github`
+    words: HashMap<String, String>,
+    /// The keys in `word_map`, the other spellings are left to `spellcheck`, e.g.: `nodejs = Node.js`
+    keys: HashSet<String>,
+    /// The candidates `(key, word)` grouped by the first char and the chars count of key.
+    candidates: HashMap<(char, usize), Vec<(String, String)>>,
+}
+
+impl FuzzyIndex {
+    pub(crate) fn new(word_map: &HashMap<String, String>) -> Self {
+        let mut index = FuzzyIndex::default();
+
+        for (key, value) in word_map.iter() {
+            index.words.insert(value.to_lowercase(), value.clone());
+            index.keys.insert(key.clone());
+
+            let key_len = key.chars().count();
+            if key_len < FUZZY_MIN_LEN {
+                continue;
+            }
+            if let Some(first) = key.chars().next() {
+                index
+                    .candidates
+                    .entry((first, key_len))
+                    .or_default()
+                    .push((key.clone(), value.clone()));
+            }
+        }
+
+        index
+    }
+}
+
+fn replace_with_suggestions(text: &str, index: &FuzzyIndex) -> String {
+    if index.keys.is_empty() {
+        return text.to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut last_end = 0;
+    for matched in FUZZY_WORD_RE.find_iter(text) {
+        let l_c = text[..matched.start()].chars().last();
+        let r_c = text[matched.end()..].chars().next();
+        if !is_fuzzy_boundary(l_c) || l_c == Some('.') || !is_fuzzy_boundary(r_c) {
+            continue;
+        }
+
+        if let Some(suggestion) = suggest_word(matched.as_str(), index) {
+            out.push_str(&text[last_end..matched.start()]);
+            out.push_str(suggestion);
+            last_end = matched.end();
+        }
+    }
+    out.push_str(&text[last_end..]);
+
+    out
+}
+
+/// Skip the word in code, path, URL or quotes, e.g.: `foo_javascirpt`, `/javascirpt`, `"Javascirpt"`
+fn is_fuzzy_boundary(c: Option<char>) -> bool {
+    match c {
+        Some(c) => {
+            !c.is_ascii_alphanumeric()
+                && !matches!(
+                    c,
+                    '_' | '-' | '/' | '\\' | '@' | '#' | '$' | '=' | '`' | '\'' | '"'
+                )
+        }
+        None => true,
+    }
+}
+
+/// Find the dictionary word only differs in case, or the closest one within a small edit distance.
+fn suggest_word<'a>(raw_word: &str, index: &'a FuzzyIndex) -> Option<&'a str> {
+    let word = raw_word.to_lowercase();
+
+    // The case-only differences, e.g.: `Github` -> `GitHub`
+    if let Some(value) = index.words.get(&word) {
+        return (value != raw_word).then_some(value.as_str());
+    }
+
+    // The other spellings in dictionary are fixed by the `spellcheck` rule.
+    if index.keys.contains(&word) {
+        return None;
+    }
+
+    let word_len = word.chars().count();
+    if word_len < FUZZY_MIN_LEN - 1 {
+        return None;
+    }
+
+    let first = word.chars().next()?;
+    let lens = word_len.saturating_sub(2)..=word_len + 2;
+    lens.filter_map(|len| index.candidates.get(&(first, len)))
+        .flatten()
+        .filter_map(|(key, value)| {
+            let key_len = key.chars().count();
+
+            // Plural words, e.g.: `iPhones`
+            if word
+                .strip_prefix(key.as_str())
+                .is_some_and(|s| s == "s" || s == "es")
+            {
+                return None;
+            }
+
+            let max_distance = if key_len >= FUZZY_LONG_LEN { 2 } else { 1 };
+            if key_len.abs_diff(word_len) > max_distance {
+                return None;
+            }
+
+            let distance = edit_distance(&word, key);
+            if distance > max_distance {
+                return None;
+            }
+
+            Some((distance, key, value))
+        })
+        .min_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(b.1)))
+        .map(|(_, _, value)| value.as_str())
+}
+
+/// The edit distance of Optimal String Alignment, the transposition of two adjacent chars is 1 edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d[a.len()][b.len()]
 }
 
 #[derive(Debug)]
@@ -29,21 +219,38 @@ fn replace_with_spans(
     text: &str,
     words: &MatchedResult,
     word_map: &HashMap<String, String>,
+    cjk_keys: &HashSet<String>,
+    excluded_spans: &[Span],
 ) -> String {
     let mut span_infos = vec![];
 
     for (old, spans) in words {
         if let Some(new) = word_map.get(old) {
             for span in spans {
+                // Skip the word in excluded compound word, e.g.: `登陆` in `登陆舰`
+                if excluded_spans
+                    .iter()
+                    .any(|ex| ex.start <= span.start && span.end <= ex.end)
+                {
+                    continue;
+                }
+
                 span_infos.push(SpanInfo { old, new, span })
             }
         }
     }
 
-    span_infos.sort_by(|a, b| a.span.start.cmp(&b.span.start));
+    // Sort by start, and the longer first
+    span_infos.sort_by(|a, b| {
+        a.span
+            .start
+            .cmp(&b.span.start)
+            .then(b.span.end.cmp(&a.span.end))
+    });
 
     let mut text_chars = text.chars().collect::<Vec<_>>();
-    let mut offset_change = 0;
+    let mut offset_change: isize = 0;
+    let mut last_end = 0;
 
     for span_info in span_infos.iter() {
         let old_str = span_info.old;
@@ -51,17 +258,29 @@ fn replace_with_spans(
         let span_start = span_info.span.start;
         let span_end = span_info.span.end;
 
-        let offset_start = span_start + offset_change;
-        let offset_end = span_end + offset_change;
+        // Skip the overlapped span, it has been replaced.
+        if span_start < last_end {
+            continue;
+        }
+
+        let offset_start = span_start.saturating_add_signed(offset_change);
+        let offset_end = span_end.saturating_add_signed(offset_change);
 
         // Check whether the left and right 1 characters are allowed
         // If not allowed, skip this replacement
-        let l_c = if offset_start == 0 {
+        //
+        // The CJK side of word in `cjkWords` has no boundary, e.g.: `帐号` in `帐号abc`
+        let is_cjk_word = cjk_keys.contains(old_str);
+        let l_c = if offset_start == 0 || (is_cjk_word && is_cjk_char(old_str.chars().next())) {
             None
         } else {
             text_chars.get(offset_start - 1)
         };
-        let r_c = text_chars.get(offset_end);
+        let r_c = if is_cjk_word && is_cjk_char(old_str.chars().last()) {
+            None
+        } else {
+            text_chars.get(offset_end)
+        };
 
         if DISALLOW_CHAR_RE.is_match(&l_c.unwrap_or(&' ').to_string())
             || DISALLOW_CHAR_RE.is_match(&r_c.unwrap_or(&' ').to_string())
@@ -73,14 +292,19 @@ fn replace_with_spans(
         // Perform replacement
         let new_str_chars = new_str.chars().collect::<Vec<_>>();
         text_chars.splice(offset_start..offset_end, new_str_chars);
+        last_end = span_end;
 
         // Update offset_change due to length change after replacement
-        offset_change += new_str.chars().count() - old_str.chars().count();
+        offset_change += new_str.chars().count() as isize - old_str.chars().count() as isize;
     }
 
     text_chars.into_iter().collect::<String>()
 }
 
+fn is_cjk_char(c: Option<char>) -> bool {
+    c.is_some_and(|c| CJK_RE.is_match(&c.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Config;
@@ -163,11 +387,171 @@ mod tests {
         assert_spellcheck_cases(cases);
     }
 
+    #[test]
+    fn test_spellcheck_cjk_words() {
+        let config = Config::from_str(indoc::indoc! {r#"
+        spellcheck:
+          words:
+            - iOS
+          cjkWords:
+            - 帐号 = 账号
+            - 登陆 = 登录
+            - 其它 = 其他
+            - 登陆页面 = 登录页
+            - "!登陆舰"
+            - "!登陆艇"
+        "#})
+        .unwrap();
+        let last_config = crate::config::set_document_config(Some(&config)).unwrap();
+
+        let cases = map! [
+            "请登陆帐号" => "请登录账号",
+            "帐号:admin" => "账号:admin",
+            "QQ帐号和其它帐号" => "QQ账号和其他账号",
+            "登陆页面和其它页面" => "登录页和其他页面",
+            "打开登陆页面，登陆ios帐号" => "打开登录页，登录iOS账号",
+            "登陆舰和登陆艇已经登陆" => "登陆舰和登陆艇已经登录",
+            "使用 ios 登陆" => "使用 iOS 登录",
+            "ios登陆" => "iOS登录"
+        ];
+        assert_spellcheck_cases(cases);
+
+        crate::config::restore_document_config(last_config);
+    }
+
+    #[test]
+    fn test_spellcheck_words_keep_boundary() {
+        let config = Config::from_str(indoc::indoc! {r#"
+        spellcheck:
+          words:
+            - iOS
+            - 帐号 = 账号
+        "#})
+        .unwrap();
+        let last_config = crate::config::set_document_config(Some(&config)).unwrap();
+
+        let cases = map! [
+            "ios" => "iOS",
+            "使用 ios 登录" => "使用 iOS 登录",
+            "ios登录" => "iOS登录",
+            "开放接口 IOS，" => "开放接口 iOS，",
+            "hello_ios" => "hello_ios",
+            "openios" => "openios",
+            "this-is-ios" => "this-is-ios",
+            "https://ios.com" => "https://ios.com",
+            "帐号密码" => "账号密码",
+            "QQ帐号" => "QQ帐号",
+            "帐号admin" => "帐号admin"
+        ];
+        assert_spellcheck_cases(cases);
+
+        crate::config::restore_document_config(last_config);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(0, edit_distance("javascript", "javascript"));
+        assert_eq!(1, edit_distance("javascirpt", "javascript"));
+        assert_eq!(1, edit_distance("javascrit", "javascript"));
+        assert_eq!(1, edit_distance("javasscript", "javascript"));
+        assert_eq!(2, edit_distance("javscirpt", "javascript"));
+        assert_eq!(3, edit_distance("kitten", "sitting"));
+        assert_eq!(5, edit_distance("", "hello"));
+    }
+
+    #[test]
+    fn test_spellcheck_fuzzy() {
+        let mut config = Config::default();
+        config.spellcheck.words = vec![
+            "JavaScript".into(),
+            "GitHub".into(),
+            "iPhone".into(),
+            "iOS".into(),
+            "PostgreSQL".into(),
+            "nodejs = Node.js".into(),
+        ];
+        config.spellcheck.prepare();
+        let index = config.spellcheck.fuzzy_index();
+
+        let cases = map! [
+            "Javascirpt" => "JavaScript",
+            "学习 Javascirpt 开发" => "学习 JavaScript 开发",
+            "use Javascrpt, and Githbu." => "use JavaScript, and GitHub.",
+            "Postgresq and PostgerSQL" => "PostgreSQL and PostgreSQL",
+            "JavaScript and Github" => "JavaScript and GitHub",
+            "nodejs and Node.JS" => "nodejs and Node.js",
+            "Node.js and nodjes" => "Node.js and Node.js",
+            "iPhones and iphon" => "iPhones and iPhone",
+            "iOs and iSO" => "iOS and iSO",
+            "Javascripts" => "Javascripts",
+            "Javascript_test foo.javascirpt javascirpt.js" => "Javascript_test foo.javascirpt javascirpt.js",
+            "https://githbu.com/foo" => "https://githbu.com/foo",
+            r#""Javascirpt" `Javascirpt`"# => r#""Javascirpt" `Javascirpt`"#,
+            "hello world" => "hello world"
+        ];
+
+        for (source, expected) in cases {
+            assert_eq!(
+                expected,
+                replace_with_suggestions(source, index),
+                "{source}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_spellcheck_fuzzy_lint_only() {
+        let config = Config::from_str(indoc::indoc! {r#"
+        rules:
+          spellcheck-fuzzy: 1
+        spellcheck:
+          words:
+            - JavaScript
+        "#})
+        .unwrap();
+        let last_config = crate::config::set_document_config(Some(&config)).unwrap();
+
+        let result = crate::rule::format_or_lint("学习 Javascirpt 开发", false);
+        assert_eq!("学习 Javascirpt 开发", result.out);
+        assert!(result.severity.is_pass());
+
+        let result = crate::rule::format_or_lint("学习 Javascirpt 开发", true);
+        assert_eq!("学习 JavaScript 开发", result.out);
+        assert!(result.severity.is_warning());
+
+        crate::config::restore_document_config(last_config);
+    }
+
+    #[test]
+    fn test_spellcheck_presets() {
+        let config = Config::from_str(indoc::indoc! {r#"
+        spellcheck:
+          presets:
+            - tech-brands
+            - apple
+            - cloud
+        "#})
+        .unwrap();
+        let last_config = crate::config::set_document_config(Some(&config)).unwrap();
+
+        let word_map = Config::effective().spellcheck.word_map.clone();
+        for (left, right) in word_map.iter() {
+            assert_eq!(right, &format(left));
+            assert_eq!(right, &format(&left.to_uppercase()));
+            assert_eq!(
+                format!("使用 {right} 开发"),
+                format(&format!("使用 {left} 开发"))
+            );
+        }
+
+        crate::config::restore_document_config(last_config);
+    }
+
     #[test]
     fn test_spellcheck_all() {
         crate::config::setup_test();
 
-        let words = Config::current().spellcheck.words.clone();
+        let words = Config::effective().spellcheck.words.clone();
         for l in words.iter() {
             let (left, right) = if l.contains('=') {
                 let pars = l.split('=').collect::<Vec<_>>();
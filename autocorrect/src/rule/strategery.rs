@@ -11,6 +11,8 @@ pub struct Strategery {
     add_space_reverse_re: regex::Regex,
     remove_space_re: regex::Regex,
     remove_space_reverse_re: regex::Regex,
+    /// Keep without space when the end of the left part and the start of the right part (with the following text) are matched.
+    except_res: Option<(regex::Regex, regex::Regex)>,
 }
 
 impl Strategery {
@@ -23,9 +25,16 @@ impl Strategery {
             add_space_reverse_re: regexp!("({})({})", other, one),
             remove_space_re: regexp!("({})[ ]+({})", one, other),
             remove_space_reverse_re: regexp!("({})[ ]+({})", other, one),
+            except_res: None,
         }
     }
 
+    // Set the exception to keep without space, e.g.: the Korean particles after English word `Rust를`.
+    pub fn with_except(mut self, left: &'static str, right: &'static str) -> Self {
+        self.except_res = Some((regexp!("(?:{})$", left), regexp!("^(?:{})", right)));
+        self
+    }
+
     // Set Strategery for remove space.
     pub fn with_remove_space(mut self) -> Self {
         self.space_mode = SpaceMode::Remove;
@@ -46,18 +55,31 @@ impl Strategery {
     }
 
     fn add_space(&self, text: &str) -> String {
-        let mut out = self.add_space_re.replace_all(text, "$1 $2").to_string();
+        let mut out = self.add_space_with(&self.add_space_re, text);
 
         if self.reverse {
-            out = self
-                .add_space_reverse_re
-                .replace_all(&out, "$1 $2")
-                .to_string();
+            out = self.add_space_with(&self.add_space_reverse_re, &out);
         }
 
         out
     }
 
+    fn add_space_with(&self, re: &regex::Regex, text: &str) -> String {
+        let Some((left_re, right_re)) = &self.except_res else {
+            return re.replace_all(text, "$1 $2").to_string();
+        };
+
+        re.replace_all(text, |cap: &regex::Captures| {
+            let (left, right) = (&cap[1], cap.get(2).unwrap());
+            if left_re.is_match(left) && right_re.is_match(&text[right.start()..]) {
+                cap[0].to_string()
+            } else {
+                format!("{} {}", left, right.as_str())
+            }
+        })
+        .to_string()
+    }
+
     fn remove_space(&self, text: &str) -> String {
         // println!("--- remove space before:`{}`", text);
         let mut out = self.remove_space_re.replace_all(text, "$1$2").to_string();
@@ -0,0 +1,159 @@
+use super::rule::RuleResult;
+use crate::config::{Config, SeverityMode};
+use crate::result::Severity;
+
+/// Replace the prohibited terms with their replacements in format,
+/// only the terms in error severity (the default) will be replaced.
+pub(crate) fn format(text: &str) -> String {
+    let config = Config::effective();
+
+    let mut out = text.to_string();
+    for term in config.terms.iter() {
+        if term.severity.clone().unwrap_or(SeverityMode::Error) != SeverityMode::Error {
+            continue;
+        }
+
+        if let Some(new) = term.replace(&out) {
+            out = new;
+        }
+    }
+
+    out
+}
+
+/// Check the prohibited or discouraged terms in config for lint,
+/// the result has the messages and the severity of matched terms.
+///
+/// The severity of the term is capped by the `terms` rule severity.
+pub(crate) fn lint(text: &str) -> RuleResult {
+    let config = Config::effective();
+
+    let mut result = RuleResult::new(text);
+    for term in config.terms.iter() {
+        let severity = term.severity.clone().unwrap_or(SeverityMode::Error);
+        if severity == SeverityMode::Off {
+            continue;
+        }
+
+        let Some(regex) = &term.regex else {
+            continue;
+        };
+        if !regex.is_match(&result.out) {
+            continue;
+        }
+
+        if let Some(out) = term.replace(&result.out) {
+            result.out = out;
+        }
+        result.messages.push(term.message());
+
+        if severity == SeverityMode::Error {
+            result.severity = Severity::Error;
+        } else if result.severity == Severity::Pass {
+            result.severity = Severity::Warning;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::rule::Rule;
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_terms() {
+        let config = Config::from_str(indoc! {r#"
+        rules:
+          terms: 1
+        terms:
+          - pattern: 点击这里
+            message: Use descriptive link text.
+            severity: warning
+          - pattern: 傻瓜式
+            replacement: 简单易用
+          - pattern: master/slave
+            replacement: primary/replica
+          - pattern: /白名单|whitelist/
+            replacement: 允许列表
+            severity: warning
+          - pattern: blacklist
+            severity: off
+        "#})
+        .unwrap();
+        let last_config = crate::config::set_document_config(Some(&config)).unwrap();
+
+        let cases = [
+            (
+                "请点击这里下载",
+                false,
+                "请点击这里下载",
+                Severity::Pass,
+                vec![],
+            ),
+            (
+                "请点击这里下载",
+                true,
+                "请点击这里下载",
+                Severity::Warning,
+                vec!["Use descriptive link text."],
+            ),
+            (
+                "傻瓜式的 Master/Slave 架构",
+                false,
+                "简单易用的 primary/replica 架构",
+                Severity::Error,
+                vec![],
+            ),
+            (
+                "傻瓜式的 Master/Slave 架构",
+                true,
+                "简单易用的 primary/replica 架构",
+                Severity::Error,
+                vec![
+                    "Use `简单易用` instead of `傻瓜式`.",
+                    "Use `primary/replica` instead of `master/slave`.",
+                ],
+            ),
+            (
+                "the mastered/slaves",
+                true,
+                "the mastered/slaves",
+                Severity::Pass,
+                vec![],
+            ),
+            ("加入白名单", false, "加入白名单", Severity::Pass, vec![]),
+            (
+                "加入白名单",
+                true,
+                "加入允许列表",
+                Severity::Warning,
+                vec!["Use `允许列表` instead of `/白名单|whitelist/`."],
+            ),
+            ("blacklist", true, "blacklist", Severity::Pass, vec![]),
+        ];
+
+        let rule = Rule::new("terms", format).with_lint(lint);
+        for (input, lint, expected, severity, messages) in cases {
+            let mut result = RuleResult::new(input);
+            if lint {
+                rule.lint(&mut result);
+            } else {
+                rule.format(&mut result);
+            }
+            assert_eq!(expected, result.out, "{input}");
+            assert_eq!(severity, result.severity, "{input}");
+            assert_eq!(messages, result.messages, "{input}");
+        }
+
+        crate::config::restore_document_config(last_config);
+    }
+
+    #[test]
+    fn test_invalid_term() {
+        let err = Config::from_str("terms:\n  - pattern: /[a-/").unwrap_err();
+        assert!(err.to_string().starts_with("Invalid term pattern: /[a-/"));
+    }
+}
@@ -1,17 +1,30 @@
 // autocorrect: false
 use super::{strategery::Strategery, CJK_RE};
 
+// In Korean, the particles and copulas attach to the preceding word, so never add space between them,
+// e.g.: `Rust를`, `(Rust)를`, `GitHub에서는`, `Rust입니다`.
+const KOREAN_PARTICLE_LEFT: &str = r"[a-zA-Z0-9\)`]";
+const KOREAN_PARTICLE_RIGHT: &str = concat!(
+    r"(?:",
+    // Copulas
+    r"이다|입니다|이에요|예요|",
+    // Particles
+    r"에게서|에게|한테|께|에서|에|까지|부터|보다|처럼|이나|나|이랑|랑|하고|마다|조차|밖에|",
+    r"으로|로|를|을|은|는|이|가|의|와|과|도|만",
+    r")+(?:\s|$|[[:punct:]])"
+);
+
 lazy_static! {
     // Strategies all rules
     static ref WORD_STRATEGIES: Vec<Strategery> = vec![
         // EnglishLetter, Number
         // Avoid add space when Letter, Number has %, $, \ prefix, eg. %s, %d, $1, $2, \1, \2, \d, \r, \p ... in source code
         Strategery::new(r"\p{CJK}[^%\$\\]", r"[a-zA-Z0-9]"),
-        Strategery::new(r"[^%\$\\][a-zA-Z0-9]", r"\p{CJK}"),
+        Strategery::new(r"[^%\$\\][a-zA-Z0-9]", r"\p{CJK}").with_except(KOREAN_PARTICLE_LEFT, KOREAN_PARTICLE_RIGHT),
         // Number, -100, +100
-        Strategery::new(r"\p{CJK}", r"[\-+][\d]+").with_reverse(),
+        Strategery::new(r"\p{CJK}", r"[\-+][\d]+").with_reverse().with_except(KOREAN_PARTICLE_LEFT, KOREAN_PARTICLE_RIGHT),
         // Spcial format Letter, Number leading case, because the before Strategery can't cover eg. A开头的case测试
-        Strategery::new(r"^[a-zA-Z0-9]", r"\p{CJK}"),
+        Strategery::new(r"^[a-zA-Z0-9]", r"\p{CJK}").with_except(KOREAN_PARTICLE_LEFT, KOREAN_PARTICLE_RIGHT),
         // 10%中文
         Strategery::new(r"[0-9][%]", r"\p{CJK}"),
         // 300+单词，A+评分，C++中文，C#中文, 100#中文
@@ -29,13 +42,13 @@ lazy_static! {
     static ref BRACKETS_STRATEGIES: Vec<Strategery> = vec![
         // Add space before and after brackets [] or () near the CJK
         Strategery::new(r"\p{CJK}", r"[\[\(]"),
-        Strategery::new(r"[\]\)]", r"\p{CJK}"),
+        Strategery::new(r"[\]\)]", r"\p{CJK}").with_except(KOREAN_PARTICLE_LEFT, KOREAN_PARTICLE_RIGHT),
     ];
 
     static ref BACKTICKS_STRATEGIES: Vec<Strategery> = vec![
         // Add space before and after backtick ` near the CJK
         Strategery::new(r"\p{CJK}", r"`.+`"),
-        Strategery::new(r"`.+`", r"\p{CJK}"),
+        Strategery::new(r"`.+`", r"\p{CJK}").with_except(KOREAN_PARTICLE_LEFT, KOREAN_PARTICLE_RIGHT),
     ];
 
     static ref DASH_STRATEGIES: Vec<Strategery> = vec![
@@ -44,6 +57,30 @@ lazy_static! {
         Strategery::new(r"[\p{CJK_N}\s）】」”’》][\-]", r"[\p{CJK_N}“‘]"),
     ];
 
+    // Strategies for `remove` space policy, e.g.: for Japanese.
+    //
+    // Keep the spaces in Korean, because the words in Korean are separated by spaces.
+    static ref REMOVE_WORD_STRATEGIES: Vec<Strategery> = vec![
+        Strategery::new(r"\p{CJ}", r"[a-zA-Z0-9]").with_remove_space().with_reverse(),
+        Strategery::new(r"\p{CJ}", r"[\-+][\d]+").with_remove_space(),
+        Strategery::new(r"[0-9][%]|[a-zA-Z0-9][+#]+", r"\p{CJ}").with_remove_space(),
+    ];
+
+    static ref REMOVE_BRACKETS_STRATEGIES: Vec<Strategery> = vec![
+        Strategery::new(r"\p{CJ}", r"[\[\(]").with_remove_space(),
+        Strategery::new(r"[\]\)]", r"\p{CJ}").with_remove_space(),
+    ];
+
+    static ref REMOVE_BACKTICKS_STRATEGIES: Vec<Strategery> = vec![
+        Strategery::new(r"\p{CJ}", r"`[^`]+`").with_remove_space().with_reverse(),
+    ];
+
+    static ref REMOVE_DASH_STRATEGIES: Vec<Strategery> = vec![
+        // Only the dash between CJK, e.g.: `東京 - 大阪` -> `東京-大阪`
+        Strategery::new(r"[\p{CJ_N}”’]", r"[\-][ ]*[\p{CJ_N}“‘]").with_remove_space(),
+        Strategery::new(r"[\p{CJ_N}”’][\-]", r"[\p{CJ_N}“‘]").with_remove_space(),
+    ];
+
     static ref NO_SPACE_FULLWIDTH_STRATEGIES: Vec<Strategery> = vec![
         // FullwidthPunctuation remove space case, Fullwidth can safe to remove spaces
         Strategery::new(r"\w|\p{CJK}|`", r"[，。、！？：；（）「」《》【】]").with_remove_space().with_reverse(),
@@ -91,6 +128,38 @@ pub fn format_space_backticks(input: &str) -> String {
     out
 }
 
+pub fn remove_space_word(input: &str) -> String {
+    let mut out = String::from(input);
+    REMOVE_WORD_STRATEGIES
+        .iter()
+        .for_each(|s| out = s.format(&out));
+    out
+}
+
+pub fn remove_space_bracket(input: &str) -> String {
+    let mut out = String::from(input);
+    REMOVE_BRACKETS_STRATEGIES
+        .iter()
+        .for_each(|s| out = s.format(&out));
+    out
+}
+
+pub fn remove_space_dash(input: &str) -> String {
+    let mut out = String::from(input);
+    REMOVE_DASH_STRATEGIES
+        .iter()
+        .for_each(|s| out = s.format(&out));
+    out
+}
+
+pub fn remove_space_backticks(input: &str) -> String {
+    let mut out = String::from(input);
+    REMOVE_BACKTICKS_STRATEGIES
+        .iter()
+        .for_each(|s| out = s.format(&out));
+    out
+}
+
 pub fn format_no_space_fullwidth(input: &str) -> String {
     let mut out = String::from(input);
 
@@ -119,7 +188,89 @@ pub fn format_no_space_fullwidth_quote(input: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use crate::rule::word::{format_space_backticks, format_space_dash};
+    use crate::rule::word::{
+        format_space_backticks, format_space_bracket, format_space_dash, format_space_word,
+        remove_space_backticks, remove_space_bracket, remove_space_dash, remove_space_word,
+    };
+
+    #[test]
+    fn test_format_space_word_with_korean_particles() {
+        assert_eq!(
+            format_space_word("프로덕션 환경에서Rust를 사용하여"),
+            "프로덕션 환경에서 Rust를 사용하여"
+        );
+        assert_eq!(format_space_word("Cloudflare와 같이"), "Cloudflare와 같이");
+        assert_eq!(format_space_word("API에서 3만 명이"), "API에서 3만 명이");
+        assert_eq!(
+            format_space_word("Rust는, -100이 된다."),
+            "Rust는, -100이 된다."
+        );
+        assert_eq!(format_space_word("Rust로"), "Rust로");
+        assert_eq!(
+            format_space_word("GitHub에서는 Rust입니다, API에게"),
+            "GitHub에서는 Rust입니다, API에게"
+        );
+        assert_eq!(
+            format_space_word("Rust에서도 Go까지 C부터"),
+            "Rust에서도 Go까지 C부터"
+        );
+        assert_eq!(
+            format_space_word("HTTP으로는 안 됩니다"),
+            "HTTP으로는 안 됩니다"
+        );
+        assert_eq!(format_space_word("Rust처럼, Go보다"), "Rust처럼, Go보다");
+        assert_eq!(format_space_word("Rust이다. Go예요"), "Rust이다. Go예요");
+        assert_eq!(format_space_word("Rust이에요"), "Rust이에요");
+        assert_eq!(
+            format_space_bracket("변경(Change)을 적용"),
+            "변경 (Change)을 적용"
+        );
+        assert_eq!(format_space_backticks("`cargo`를 실행"), "`cargo`를 실행");
+
+        // Not the particle
+        assert_eq!(format_space_word("Python코드를 작성"), "Python 코드를 작성");
+        assert_eq!(
+            format_space_word("Rust프로그래밍 언어"),
+            "Rust 프로그래밍 언어"
+        );
+        assert_eq!(format_space_word("Rust를이용"), "Rust 를이용");
+        assert_eq!(format_space_word("3개의"), "3 개의");
+
+        // The other CJK are not changed
+        assert_eq!(format_space_word("使用Rust的"), "使用 Rust 的");
+        assert_eq!(format_space_word("企業がRustを採用"), "企業が Rust を採用");
+    }
+
+    #[test]
+    fn test_remove_space() {
+        assert_eq!(remove_space_word("使用 Rust 的"), "使用Rust的");
+        assert_eq!(
+            remove_space_word("企業が Rust 1 を採用"),
+            "企業がRust 1を採用"
+        );
+        assert_eq!(
+            remove_space_word("増加 +100 件、10% 以上"),
+            "増加+100件、10%以上"
+        );
+        assert_eq!(remove_space_word("C++ 言語"), "C++言語");
+        assert_eq!(remove_space_word("Rust 사용"), "Rust 사용");
+        assert_eq!(remove_space_word("Hello world"), "Hello world");
+
+        assert_eq!(
+            remove_space_bracket("変更 (Change) を適用"),
+            "変更(Change)を適用"
+        );
+        assert_eq!(remove_space_bracket("foo (bar) baz"), "foo (bar) baz");
+
+        assert_eq!(remove_space_dash("東京 - 大阪"), "東京-大阪");
+        assert_eq!(remove_space_dash("foo - 大阪"), "foo - 大阪");
+
+        assert_eq!(
+            remove_space_backticks("実行 `cargo` して `rustc` を"),
+            "実行`cargo`して`rustc`を"
+        );
+        assert_eq!(remove_space_backticks("`a` b `c`"), "`a` b `c`");
+    }
 
     #[test]
     fn test_format_space_dash() {